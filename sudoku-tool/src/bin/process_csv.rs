@@ -1,12 +1,24 @@
-use clap::Parser;
-use csv::{Reader, Writer};
+use clap::{Parser, ValueEnum};
+use csv::{Reader, Writer, WriterBuilder};
+use log::{debug, info, warn};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::error::Error;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 use sudoku_tool::core::solvers::bf_solver::{SolverStats, find_one_solution};
 use sudoku_tool::core::sudoku::Sudoku;
 
+// Puzzles are solved in chunks of this size so a huge input file never
+// holds more than one chunk's worth of in-flight solver work/results in
+// memory at once, while still writing results back in input order.
+const BATCH_SIZE: usize = 1000;
+
 /*
     CLI Command format:
         Required arguments: -i for input path and -o for output path
@@ -16,6 +28,15 @@ use sudoku_tool::core::sudoku::Sudoku;
             -s for the sample (process every nth puzzle)
             -seed for a random seed for sampling
             -p show progress every n puzzles
+            -f / --format output format: csv (default), json, or ndjson
+            -t / --threads cap the rayon worker pool used to solve puzzles
+            -v / --verbose raise log verbosity (-v for debug, -vv for trace);
+                 set RUST_LOG to override the level directly
+            --resume continue an interrupted run: skip input ids already
+                 present in the output file and append new rows instead of
+                 overwriting it
+            --timing report a parse/solve/write wall-clock breakdown and
+                 p50/p95/p99 solve-time percentiles at completion
 
 */
 #[derive(Parser)]
@@ -45,6 +66,100 @@ struct Cli {
     /// Show progress every N puzzles
     #[arg(short, long, default_value_t = 1000)]
     progress: usize,
+
+    /// Output format
+    #[arg(short = 'f', long, value_enum, default_value_t = OutputFormat::Csv)]
+    format: OutputFormat,
+
+    /// Number of worker threads for the rayon pool (defaults to rayon's automatic choice)
+    #[arg(short, long)]
+    threads: Option<usize>,
+
+    /// Increase log verbosity (-v for debug, -vv for trace); RUST_LOG overrides this
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Resume an interrupted run: skip input ids already present in the
+    /// output file and append new rows instead of overwriting it
+    #[arg(long)]
+    resume: bool,
+
+    /// Report a parse/solve/write timing breakdown and solve-time
+    /// percentiles at completion
+    #[arg(long)]
+    timing: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Csv,
+    Json,
+    Ndjson,
+}
+
+// Accumulates wall-clock time spent in each named phase of the pipeline
+// (parse, solve, write) across however many rayon worker threads are
+// solving puzzles concurrently. Nanoseconds in an AtomicU64 rather than a
+// mutex-guarded Duration, since every puzzle in a batch adds to it from
+// whichever thread solved that puzzle.
+#[derive(Default)]
+struct PhaseTimers {
+    parse_nanos: AtomicU64,
+    solve_nanos: AtomicU64,
+    write_nanos: AtomicU64,
+}
+
+impl PhaseTimers {
+    fn add_parse(&self, d: Duration) {
+        self.parse_nanos.fetch_add(d.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn add_solve(&self, d: Duration) {
+        self.solve_nanos.fetch_add(d.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn add_write(&self, d: Duration) {
+        self.write_nanos.fetch_add(d.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    // Logs each phase's share of the combined parse+solve+write time. This
+    // is wall-clock time *summed across worker threads*, so it reflects
+    // where the work went, not how long the run actually took (which
+    // `rayon` overlaps across cores).
+    fn report(&self) {
+        let parse = self.parse_nanos.load(Ordering::Relaxed);
+        let solve = self.solve_nanos.load(Ordering::Relaxed);
+        let write = self.write_nanos.load(Ordering::Relaxed);
+        let total = (parse + solve + write).max(1);
+
+        info!("Timing breakdown (summed across worker threads):");
+        info!(
+            "  parse: {:>8.2} ms ({:>5.1}%)",
+            parse as f64 / 1_000_000.0,
+            parse as f64 / total as f64 * 100.0
+        );
+        info!(
+            "  solve: {:>8.2} ms ({:>5.1}%)",
+            solve as f64 / 1_000_000.0,
+            solve as f64 / total as f64 * 100.0
+        );
+        info!(
+            "  write: {:>8.2} ms ({:>5.1}%)",
+            write as f64 / 1_000_000.0,
+            write as f64 / total as f64 * 100.0
+        );
+    }
+}
+
+// Nearest-rank percentile over per-puzzle solve times; `times` is sorted
+// in place since the caller has no further use for the original order.
+fn percentile_ms(times: &mut [u128], p: f64) -> u128 {
+    if times.is_empty() {
+        return 0;
+    }
+    times.sort_unstable();
+    let rank = ((p / 100.0) * times.len() as f64).ceil() as usize;
+    times[rank.saturating_sub(1).min(times.len() - 1)]
 }
 
 #[derive(Debug, Deserialize)]
@@ -73,34 +188,260 @@ struct OutputStats {
     leaves: usize,
 }
 
+// Wraps the three supported output formats behind one write/finish
+// interface so `process_puzzles`'s main loop doesn't need to branch on
+// `cli.format` for every record.
+enum OutputSink {
+    Csv(Writer<File>),
+    Json {
+        path: PathBuf,
+        records: Vec<OutputStats>,
+    },
+    Ndjson(BufWriter<File>),
+}
+
+impl OutputSink {
+    // `append` means an existing output file is being continued (via
+    // --resume) rather than overwritten, so the CSV header is skipped and
+    // NDJSON opens in append mode; `existing_json_records` seeds the JSON
+    // sink so `finish` rewrites the combined array rather than losing the
+    // previous run's rows.
+    fn new(
+        format: OutputFormat,
+        path: &PathBuf,
+        append: bool,
+        existing_json_records: Vec<OutputStats>,
+    ) -> Result<Self, Box<dyn Error>> {
+        Ok(match format {
+            OutputFormat::Csv => {
+                if append {
+                    let file = OpenOptions::new().append(true).open(path)?;
+                    OutputSink::Csv(WriterBuilder::new().has_headers(false).from_writer(file))
+                } else {
+                    OutputSink::Csv(Writer::from_path(path)?)
+                }
+            }
+            OutputFormat::Json => OutputSink::Json {
+                path: path.clone(),
+                records: existing_json_records,
+            },
+            OutputFormat::Ndjson => {
+                let file = if append {
+                    OpenOptions::new().append(true).open(path)?
+                } else {
+                    File::create(path)?
+                };
+                OutputSink::Ndjson(BufWriter::new(file))
+            }
+        })
+    }
+
+    fn write_record(&mut self, record: OutputStats) -> Result<(), Box<dyn Error>> {
+        match self {
+            OutputSink::Csv(wtr) => wtr.serialize(record)?,
+            OutputSink::Json { records, .. } => records.push(record),
+            OutputSink::Ndjson(wtr) => {
+                serde_json::to_writer(&mut *wtr, &record)?;
+                wtr.write_all(b"\n")?;
+            }
+        }
+        Ok(())
+    }
+
+    // Flush periodically so a killed run still leaves valid partial
+    // output. JSON is a single top-level array and can't be flushed
+    // incrementally, so it's written whole in `finish`.
+    fn flush(&mut self) -> Result<(), Box<dyn Error>> {
+        match self {
+            OutputSink::Csv(wtr) => wtr.flush()?,
+            OutputSink::Json { .. } => {}
+            OutputSink::Ndjson(wtr) => wtr.flush()?,
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), Box<dyn Error>> {
+        match self {
+            OutputSink::Csv(mut wtr) => wtr.flush()?,
+            OutputSink::Json { path, records } => {
+                let file = File::create(path)?;
+                serde_json::to_writer_pretty(file, &records)?;
+            }
+            OutputSink::Ndjson(mut wtr) => wtr.flush()?,
+        }
+        Ok(())
+    }
+}
+
+// What --resume found in an existing output file: the ids already written
+// (so their input rows are skipped) and, for the JSON format, the records
+// themselves (since `finish` rewrites the whole array rather than
+// appending).
+#[derive(Default)]
+struct ResumeState {
+    already_processed: HashSet<u32>,
+    existing_json_records: Vec<OutputStats>,
+}
+
+// Reads whatever the previous run left behind and recovers the ids it
+// already wrote. CSV/NDJSON are read line by line so a row left
+// half-written by a crash or Ctrl-C can be detected and dropped rather than
+// either corrupting the resumed file or causing that id to be silently
+// skipped forever; the file is rewritten without that trailing line so the
+// appended output stays valid.
+fn scan_existing_output(format: OutputFormat, path: &PathBuf) -> Result<ResumeState, Box<dyn Error>> {
+    if !path.exists() {
+        return Ok(ResumeState::default());
+    }
+
+    match format {
+        OutputFormat::Csv => {
+            let content = fs::read_to_string(path)?;
+            let lines: Vec<&str> = content.lines().collect();
+            if lines.is_empty() {
+                return Ok(ResumeState::default());
+            }
+
+            // A row is complete only if it has all ten OutputStats fields
+            // and, as the file's last line, ends with a newline.
+            let ends_with_newline = content.ends_with('\n');
+            let mut already_processed = HashSet::new();
+            let mut valid_lines = 1; // the header line is always kept
+            for (n, line) in lines.iter().enumerate().skip(1) {
+                let fields: Vec<&str> = line.split(',').collect();
+                let complete = fields.len() == 10 && (ends_with_newline || n + 1 < lines.len());
+                match fields[0].parse::<u32>().ok().filter(|_| complete) {
+                    Some(id) => {
+                        already_processed.insert(id);
+                        valid_lines += 1;
+                    }
+                    None => break, // truncated/corrupt trailing row; drop and recompute it
+                }
+            }
+
+            if valid_lines < lines.len() {
+                warn!(
+                    "dropping {} truncated trailing line(s) from {:?} before resuming",
+                    lines.len() - valid_lines,
+                    path
+                );
+                fs::write(path, lines[..valid_lines].join("\n") + "\n")?;
+            }
+
+            Ok(ResumeState { already_processed, existing_json_records: Vec::new() })
+        }
+        OutputFormat::Ndjson => {
+            let content = fs::read_to_string(path)?;
+            let lines: Vec<&str> = content.lines().collect();
+
+            let mut already_processed = HashSet::new();
+            let mut valid_lines = 0;
+            for line in &lines {
+                match serde_json::from_str::<OutputStats>(line) {
+                    Ok(record) => {
+                        already_processed.insert(record.id);
+                        valid_lines += 1;
+                    }
+                    Err(_) => break, // truncated/corrupt trailing row; drop and recompute it
+                }
+            }
+
+            if valid_lines < lines.len() {
+                warn!(
+                    "dropping {} truncated trailing line(s) from {:?} before resuming",
+                    lines.len() - valid_lines,
+                    path
+                );
+                let recovered = if valid_lines == 0 {
+                    String::new()
+                } else {
+                    lines[..valid_lines].join("\n") + "\n"
+                };
+                fs::write(path, recovered)?;
+            }
+
+            Ok(ResumeState { already_processed, existing_json_records: Vec::new() })
+        }
+        OutputFormat::Json => {
+            // Only ever written whole, in `finish`, so there's no partial
+            // row to recover here: either it parses, or it's left over
+            // from a completed run.
+            let content = fs::read_to_string(path)?;
+            match serde_json::from_str::<Vec<OutputStats>>(&content) {
+                Ok(records) => {
+                    let already_processed = records.iter().map(|r| r.id).collect();
+                    Ok(ResumeState { already_processed, existing_json_records: records })
+                }
+                Err(_) => {
+                    warn!("existing JSON output at {:?} could not be parsed; starting fresh", path);
+                    Ok(ResumeState::default())
+                }
+            }
+        }
+    }
+}
+
 fn process_puzzles(cli: &Cli) -> Result<(), Box<dyn Error>> {
     let mut rdr = Reader::from_path(&cli.input)?;
-    let mut wtr = Writer::from_path(&cli.output)?;
-    
-    // Write header
-    wtr.serialize(OutputStats {
-        id: 0,
-        puzzle: "puzzle".to_string(),
-        clues: 0,
-        difficulty: 0.0,
-        solutions_found: 0,
-        nodes_explored: 0,
-        max_recursion_depth: 0,
-        solve_time_ms: 0,
-        is_solved: false,
-        leaves: 0,
-    })?;
-    
-    let mut processed = 0;
-    let mut total_time = 0u128;
-    let mut total_nodes = 0usize;
-    
+
+    let resume_state = if cli.resume {
+        scan_existing_output(cli.format, &cli.output)?
+    } else {
+        ResumeState::default()
+    };
+    if cli.resume {
+        info!(
+            "Resuming: {} puzzle id(s) already present in {:?}",
+            resume_state.already_processed.len(),
+            cli.output
+        );
+    }
+    let appending = cli.resume && !resume_state.already_processed.is_empty();
+
+    let mut sink = OutputSink::new(cli.format, &cli.output, appending, resume_state.existing_json_records)?;
+
+    // Write header (CSV only; JSON/NDJSON records are self-describing).
+    // Skipped when appending to an existing file, since it already has one.
+    if !appending {
+        if let OutputSink::Csv(wtr) = &mut sink {
+            wtr.serialize(OutputStats {
+                id: 0,
+                puzzle: "puzzle".to_string(),
+                clues: 0,
+                difficulty: 0.0,
+                solutions_found: 0,
+                nodes_explored: 0,
+                max_recursion_depth: 0,
+                solve_time_ms: 0,
+                is_solved: false,
+                leaves: 0,
+            })?;
+        }
+    }
+
+    if let Some(threads) = cli.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .expect("failed to configure rayon thread pool");
+    }
+
     // Initialize random number generator if seed is provided
     let mut rng = cli.seed.map(|seed| {
         use rand::SeedableRng;
         rand::rngs::StdRng::seed_from_u64(seed)
     });
-    
+
+    // Select the rows to process up front. This stays serial since the
+    // sampling RNG (and --limit count) depend on reading rows in file
+    // order; the solving itself is what gets parallelized below.
+    //
+    // `sampled_count` (rather than `selected.len()`) is checked against
+    // --limit so that, when resuming, rows already present in the output
+    // still count against the original budget instead of letting a
+    // restart process --limit *more* puzzles than intended.
+    let mut selected: Vec<(usize, InputPuzzle)> = Vec::new();
+    let mut sampled_count = 0usize;
     for (i, result) in rdr.deserialize().enumerate() {
         // Apply sampling
         if cli.sample > 1 {
@@ -109,83 +450,165 @@ fn process_puzzles(cli: &Cli) -> Result<(), Box<dyn Error>> {
                 if !rng.random_ratio(1, cli.sample as u32) {
                     continue;
                 }
-            } else {
-                if i % cli.sample != 0 {
-                    continue;
-                }
+            } else if i % cli.sample != 0 {
+                continue;
             }
         }
-        
+
         // Apply limit
-        if cli.limit > 0 && processed >= cli.limit {
+        if cli.limit > 0 && sampled_count >= cli.limit {
             break;
         }
-        
+        sampled_count += 1;
+
         let record: InputPuzzle = result?;
-        processed += 1;
-        
-        // Progress reporting
-        if processed % cli.progress == 0 {
-            let avg_time = if processed > 0 { total_time / processed as u128 } else { 0 };
-            let avg_nodes = if processed > 0 { total_nodes / processed } else { 0 };
-            println!("Processed {} puzzles (avg: {} ms, {} nodes)", processed, avg_time, avg_nodes);
+        if resume_state.already_processed.contains(&record.id) {
+            continue;
         }
-        
-        // Convert string to Sudoku
-        let puzzle = match Sudoku::from_string(&record.puzzle) {
-            Ok(p) => p,
-            Err(e) => {
-                eprintln!("Skipping malformed puzzle {} (id: {}): {}", i, record.id, e);
-                continue;
+        selected.push((i, record));
+    }
+
+    if cli.resume {
+        info!(
+            "Solving {} remaining puzzle(s) ({} already done)",
+            selected.len(),
+            resume_state.already_processed.len()
+        );
+    } else {
+        info!("Solving {} selected puzzles...", selected.len());
+    }
+
+    // Atomic counter shared across the rayon worker threads so the
+    // "Processed N puzzles" line still fires roughly every --progress
+    // puzzles regardless of which thread finishes which puzzle.
+    let progress_counter = AtomicUsize::new(0);
+    let mut total_time = 0u128;
+    let mut total_nodes = 0usize;
+    let phase_timers = PhaseTimers::default();
+    let mut solve_times_ms: Vec<u128> = Vec::new();
+
+    for batch in selected.chunks(BATCH_SIZE) {
+        // Solve this batch's puzzles in parallel; each OutputStats is
+        // independent, and collecting a rayon-mapped slice preserves the
+        // batch's original order.
+        let batch_results: Vec<Option<OutputStats>> = batch
+            .par_iter()
+            .map(|(i, record)| {
+                let parse_start = Instant::now();
+                let puzzle = match Sudoku::from_string(&record.puzzle) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        warn!("skipping malformed puzzle index={} id={}: {}", i, record.id, e);
+                        return None;
+                    }
+                };
+                if cli.timing {
+                    phase_timers.add_parse(parse_start.elapsed());
+                }
+
+                let (solution, stats) = find_one_solution(&puzzle);
+                if cli.timing {
+                    phase_timers.add_solve(stats.search_duration);
+                }
+                debug!(
+                    "puzzle index={} id={}: {} nodes, {} ms",
+                    i,
+                    record.id,
+                    stats.nodes_explored,
+                    stats.search_duration.as_millis()
+                );
+
+                let count = progress_counter.fetch_add(1, Ordering::Relaxed) + 1;
+                if count % cli.progress == 0 {
+                    info!("Processed {} puzzles", count);
+                }
+
+                Some(OutputStats {
+                    id: record.id,
+                    puzzle: record.puzzle.clone(),
+                    clues: record.clues,
+                    difficulty: record.difficulty,
+                    solutions_found: stats.solutions_found,
+                    nodes_explored: stats.nodes_explored,
+                    max_recursion_depth: stats.max_recursion_depth,
+                    solve_time_ms: stats.search_duration.as_millis(),
+                    is_solved: solution.is_some(),
+                    leaves: stats.leaves,
+                })
+            })
+            .collect();
+
+        // Running totals are combined via this reduction over the
+        // collected batch, rather than mutated from inside the parallel
+        // closure above.
+        for result in batch_results.into_iter().flatten() {
+            total_time += result.solve_time_ms;
+            total_nodes += result.nodes_explored;
+            if cli.timing {
+                solve_times_ms.push(result.solve_time_ms);
+            }
+
+            let write_start = Instant::now();
+            sink.write_record(result)?;
+            if cli.timing {
+                phase_timers.add_write(write_start.elapsed());
             }
-        };
-        
-        // Solve
-        let (solution, stats) = find_one_solution(&puzzle);
-        total_time += stats.search_duration.as_millis();
-        total_nodes += stats.nodes_explored;
-        
-        // Write results
-        wtr.serialize(OutputStats {
-            id: record.id,
-            puzzle: record.puzzle,
-            clues: record.clues,
-            difficulty: record.difficulty,
-            solutions_found: stats.solutions_found,
-            nodes_explored: stats.nodes_explored,
-            max_recursion_depth: stats.max_recursion_depth,
-            solve_time_ms: stats.search_duration.as_millis(),
-            is_solved: solution.is_some(),
-            leaves: stats.leaves,
-        })?;
-        
-        // Flush periodically to avoid data loss
-        if processed % 100 == 0 {
-            wtr.flush()?;
         }
+
+        sink.flush()?;
+    }
+
+    sink.finish()?;
+
+    let processed = selected.len();
+    info!("Completed! Processed {} puzzles total", processed);
+    info!("Final averages: {} ms/puzzle, {} nodes/puzzle",
+          total_time / processed.max(1) as u128,
+          total_nodes / processed.max(1));
+
+    if cli.timing {
+        phase_timers.report();
+        info!(
+            "Solve-time percentiles: p50={} ms, p95={} ms, p99={} ms",
+            percentile_ms(&mut solve_times_ms, 50.0),
+            percentile_ms(&mut solve_times_ms, 95.0),
+            percentile_ms(&mut solve_times_ms, 99.0),
+        );
     }
-    
-    println!("Completed! Processed {} puzzles total", processed);
-    println!("Final averages: {} ms/puzzle, {} nodes/puzzle", 
-             total_time / processed.max(1) as u128,
-             total_nodes / processed.max(1));
-    
+
     Ok(())
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
     let cli = Cli::parse();
-    
-    println!("Sudoku Solver Processor");
-    println!("Input: {:?}", cli.input);
-    println!("Output: {:?}", cli.output);
-    println!("Limit: {}", if cli.limit > 0 { cli.limit.to_string() } else { "all".to_string() });
-    println!("Sampling: {}", if cli.sample > 1 { format!("1/{}", cli.sample) } else { "all".to_string() });
+
+    let default_level = match cli.verbose {
+        0 => "info",
+        1 => "debug",
+        _ => "trace",
+    };
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(default_level)).init();
+
+    info!("Sudoku Solver Processor");
+    info!("Input: {:?}", cli.input);
+    info!("Output: {:?}", cli.output);
+    info!("Limit: {}", if cli.limit > 0 { cli.limit.to_string() } else { "all".to_string() });
+    info!("Sampling: {}", if cli.sample > 1 { format!("1/{}", cli.sample) } else { "all".to_string() });
     if let Some(seed) = cli.seed {
-        println!("Random seed: {}", seed);
+        info!("Random seed: {}", seed);
+    }
+    info!("Progress reporting: every {} puzzles", cli.progress);
+    info!("Output format: {:?}", cli.format);
+    if let Some(threads) = cli.threads {
+        info!("Worker threads: {}", threads);
     }
-    println!("Progress reporting: every {} puzzles", cli.progress);
-    println!("{}", "=".repeat(50));
-    
+    if cli.resume {
+        info!("Resume: enabled");
+    }
+    if cli.timing {
+        info!("Timing breakdown: enabled");
+    }
+    info!("{}", "=".repeat(50));
+
     process_puzzles(&cli)
 }
\ No newline at end of file
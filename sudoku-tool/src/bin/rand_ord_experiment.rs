@@ -1,11 +1,13 @@
 use clap::Parser;
 use csv::{Reader, Writer};
+use log::{info, warn};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::path::PathBuf;
 
 use sudoku_tool::core::solvers::bf_solver::{
-    SolverStats, find_one_solution, find_one_solution_rand_rowcol_order,
+    find_one_solution, find_one_solution_rand_rowcol_order_seeded, has_unique_solution,
 };
 use sudoku_tool::core::sudoku::Sudoku;
 
@@ -30,6 +32,10 @@ use sudoku_tool::core::sudoku::Sudoku;
 //   -s, --seed <SEED>           Random seed for reproducible sampling
 //   -p, --progress <PROGRESS>
 //                           Show progress every N puzzles [default: 10]
+//   -t, --threads <THREADS>
+//                           Number of rayon worker threads [default: automatic]
+//   -v, --verbose           Raise log verbosity (-v for debug, -vv for trace);
+//                           RUST_LOG overrides this
 //   -h, --help              Print help information
 //
 // EXAMPLE COMMANDS:
@@ -74,6 +80,14 @@ struct Cli {
     /// Show progress every N puzzles
     #[arg(short, long, default_value_t = 10)]
     progress: usize,
+
+    /// Number of worker threads for the rayon pool (defaults to rayon's automatic choice)
+    #[arg(short, long)]
+    threads: Option<usize>,
+
+    /// Increase log verbosity (-v for debug, -vv for trace); RUST_LOG overrides this
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
 }
 
 #[derive(Debug, Deserialize)]
@@ -92,9 +106,17 @@ struct RandomRunStats {
     puzzle: String,
     clues: u8,
     difficulty: f32,
+    // Whether the puzzle has exactly one solution, so malformed or
+    // under-constrained puzzles can be filtered out before comparing
+    // solver performance across them.
+    is_well_formed: bool,
 
     // Run identification
     run_id: u32,
+    // Seed the run's cell ordering was drawn from, so any particular run
+    // (e.g. a pathological nodes_explored outlier) can be replayed exactly.
+    // `None` for the baseline run, which uses the fixed default ordering.
+    ordering_seed: Option<u64>,
 
     // Solver performance metrics
     solutions_found: usize,
@@ -116,7 +138,9 @@ fn run_random_ordering_experiment(cli: &Cli) -> Result<(), Box<dyn Error>> {
         puzzle: "puzzle".to_string(),
         clues: 0,
         difficulty: 0.0,
+        is_well_formed: false,
         run_id: 0,
+        ordering_seed: None,
         solutions_found: 0,
         nodes_explored: 0,
         max_recursion_depth: 0,
@@ -148,85 +172,117 @@ fn run_random_ordering_experiment(cli: &Cli) -> Result<(), Box<dyn Error>> {
         all_puzzles.iter().take(cli.sample_puzzles).collect()
     };
 
-    println!(
+    info!(
         "Running experiment on {} puzzles, {} runs each",
         sampled_puzzles.len(),
         cli.runs_per_puzzle
     );
 
-    for (puzzle_idx, puzzle_record) in sampled_puzzles.iter().enumerate() {
-        processed_puzzles += 1;
-
-        // Progress reporting
-        if processed_puzzles % cli.progress == 0 {
-            println!(
-                "Processing puzzle {}/{}",
-                processed_puzzles,
-                sampled_puzzles.len()
-            );
-        }
-
-        // Convert string to Sudoku
-        let puzzle = match Sudoku::from_string(&puzzle_record.puzzle) {
-            Ok(p) => p,
-            Err(e) => {
-                eprintln!(
-                    "Skipping malformed puzzle {} (id: {}): {}",
-                    puzzle_idx, puzzle_record.id, e
-                );
-                continue;
-            }
-        };
-
-        // ADDED: Run baseline with default ordering (run_id = 0)
-        let (baseline_solution, baseline_stats) = find_one_solution(&puzzle);
-
-        wtr.serialize(RandomRunStats {
-            puzzle_id: puzzle_record.id,
-            puzzle: puzzle_record.puzzle.clone(),
-            clues: puzzle_record.clues,
-            difficulty: puzzle_record.difficulty,
-            run_id: 0, // Baseline run gets ID 0
-            solutions_found: baseline_stats.solutions_found,
-            nodes_explored: baseline_stats.nodes_explored,
-            max_recursion_depth: baseline_stats.max_recursion_depth,
-            solve_time_ms: baseline_stats.search_duration.as_millis(),
-            is_solved: baseline_solution.is_some(),
-            leaves: baseline_stats.leaves,
-            backtracks: baseline_stats.backtracks,
-        })?;
-        total_runs += 1;
-
-        // Run multiple random orderings
-        for run in 1..cli.runs_per_puzzle {
-            total_runs += 1;
-
-            let (solution, stats) = find_one_solution_rand_rowcol_order(&puzzle);
+    if let Some(threads) = cli.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .expect("failed to configure rayon thread pool");
+    }
 
-            // Write results for each run
-            wtr.serialize(RandomRunStats {
+    // Base seed every run's per-(puzzle, run) seed is derived from, so the
+    // whole experiment stays reproducible regardless of which thread ends
+    // up running which puzzle.
+    let base_seed = cli.seed.unwrap_or(0);
+
+    // Run every puzzle's full set of solver calls in parallel; each
+    // puzzle's results are buffered locally so the main thread can still
+    // write them out to CSV in deterministic puzzle/run order afterwards.
+    let per_puzzle_runs: Vec<Vec<RandomRunStats>> = sampled_puzzles
+        .par_iter()
+        .enumerate()
+        .filter_map(|(puzzle_idx, puzzle_record)| {
+            let puzzle = match Sudoku::from_string(&puzzle_record.puzzle) {
+                Ok(p) => p,
+                Err(e) => {
+                    warn!(
+                        "skipping malformed puzzle index={} id={}: {}",
+                        puzzle_idx, puzzle_record.id, e
+                    );
+                    return None;
+                }
+            };
+
+            let mut runs = Vec::with_capacity(cli.runs_per_puzzle);
+            let is_well_formed = has_unique_solution(&puzzle);
+
+            // Baseline run with default ordering (run_id = 0)
+            let (baseline_solution, baseline_stats) = find_one_solution(&puzzle);
+            runs.push(RandomRunStats {
                 puzzle_id: puzzle_record.id,
                 puzzle: puzzle_record.puzzle.clone(),
                 clues: puzzle_record.clues,
                 difficulty: puzzle_record.difficulty,
-                run_id: run as u32,
-                solutions_found: stats.solutions_found,
-                nodes_explored: stats.nodes_explored,
-                max_recursion_depth: stats.max_recursion_depth,
-                solve_time_ms: stats.search_duration.as_millis(),
-                is_solved: solution.is_some(),
-                leaves: stats.leaves,
-                backtracks: stats.backtracks,
-            })?;
+                is_well_formed,
+                run_id: 0,
+                ordering_seed: None,
+                solutions_found: baseline_stats.solutions_found,
+                nodes_explored: baseline_stats.nodes_explored,
+                max_recursion_depth: baseline_stats.max_recursion_depth,
+                solve_time_ms: baseline_stats.search_duration.as_millis(),
+                is_solved: baseline_solution.is_some(),
+                leaves: baseline_stats.leaves,
+                backtracks: baseline_stats.backtracks,
+            });
+
+            // Random row/col orderings, each with its own seed derived from
+            // the base seed, puzzle id, and run number.
+            for run in 1..cli.runs_per_puzzle {
+                let run_seed = base_seed
+                    .wrapping_add((puzzle_record.id as u64).wrapping_mul(1_000_003))
+                    .wrapping_add(run as u64);
+                let (solution, stats) = find_one_solution_rand_rowcol_order_seeded(&puzzle, run_seed);
+
+                runs.push(RandomRunStats {
+                    puzzle_id: puzzle_record.id,
+                    puzzle: puzzle_record.puzzle.clone(),
+                    clues: puzzle_record.clues,
+                    difficulty: puzzle_record.difficulty,
+                    is_well_formed,
+                    run_id: run as u32,
+                    ordering_seed: Some(run_seed),
+                    solutions_found: stats.solutions_found,
+                    nodes_explored: stats.nodes_explored,
+                    max_recursion_depth: stats.max_recursion_depth,
+                    solve_time_ms: stats.search_duration.as_millis(),
+                    is_solved: solution.is_some(),
+                    leaves: stats.leaves,
+                    backtracks: stats.backtracks,
+                });
+            }
+
+            Some(runs)
+        })
+        .collect();
+
+    for runs in per_puzzle_runs {
+        processed_puzzles += 1;
+
+        for record in runs {
+            wtr.serialize(record)?;
+            total_runs += 1;
 
             // Flush periodically to avoid data loss
             if total_runs % 100 == 0 {
                 wtr.flush()?;
             }
         }
+
+        if processed_puzzles % cli.progress == 0 {
+            info!(
+                "Processing puzzle {}/{}",
+                processed_puzzles,
+                sampled_puzzles.len()
+            );
+        }
     }
 
-    println!(
+    info!(
         "Completed! Processed {} puzzles, {} total runs",
         processed_puzzles, total_runs
     );
@@ -237,16 +293,26 @@ fn run_random_ordering_experiment(cli: &Cli) -> Result<(), Box<dyn Error>> {
 fn main() -> Result<(), Box<dyn Error>> {
     let cli = Cli::parse();
 
-    println!("Random Ordering Experiment");
-    println!("Input: {:?}", cli.input);
-    println!("Output: {:?}", cli.output);
-    println!("Puzzles to sample: {}", cli.sample_puzzles);
-    println!("Runs per puzzle: {}", cli.runs_per_puzzle);
+    let default_level = match cli.verbose {
+        0 => "info",
+        1 => "debug",
+        _ => "trace",
+    };
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(default_level)).init();
+
+    info!("Random Ordering Experiment");
+    info!("Input: {:?}", cli.input);
+    info!("Output: {:?}", cli.output);
+    info!("Puzzles to sample: {}", cli.sample_puzzles);
+    info!("Runs per puzzle: {}", cli.runs_per_puzzle);
     if let Some(seed) = cli.seed {
-        println!("Random seed: {}", seed);
+        info!("Random seed: {}", seed);
+    }
+    info!("Progress reporting: every {} puzzles", cli.progress);
+    if let Some(threads) = cli.threads {
+        info!("Worker threads: {}", threads);
     }
-    println!("Progress reporting: every {} puzzles", cli.progress);
-    println!("{}", "=".repeat(50));
+    info!("{}", "=".repeat(50));
 
     run_random_ordering_experiment(&cli)
 }
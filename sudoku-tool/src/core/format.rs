@@ -0,0 +1,134 @@
+//! Parsers and writers for the portable Sudoku encodings used to pipe
+//! puzzles in from flat files and public puzzle databases, as distinct
+//! from `puzzle_parser`'s auto-detection of hand-typed/pretty grids.
+//! Covers the flat single-line digit string, the `row,col,value`
+//! coordinate list (`Sudoku::from_coords`, alongside this module's other
+//! formats), and the ksudoku-style `WxH:type:v,v,v,...` puzzle string.
+
+use nom::character::complete::one_of;
+use nom::combinator::{all_consuming, map_opt};
+use nom::multi::many1;
+use nom::IResult;
+
+use crate::core::puzzle_parser::char_to_cell;
+use crate::core::sudoku::Sudoku;
+
+// A single flat-line cell token. Unlike `puzzle_parser::flat_cell`, this
+// doesn't get its input pre-stripped of whitespace -- `from_str_line` is
+// for well-formed single-line dumps, not hand-typed grids, so stray
+// whitespace is a format error rather than padding to ignore.
+fn line_cell(input: &str) -> IResult<&str, Option<u8>> {
+    map_opt(
+        one_of("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz."),
+        char_to_cell,
+    )(input)
+}
+
+/// Parses the flat single-line format: exactly `side*side` characters,
+/// `.`/`0` for a blank cell and `1`-`9`/`A`.. for a hint, no separators.
+pub fn from_str_line(s: &str) -> Result<Sudoku, String> {
+    let trimmed = s.trim();
+    let (_, cells) = all_consuming(many1(line_cell))(trimmed)
+        .map_err(|_| format!("'{}' contains a character outside 0-9/A-Z/.", trimmed))?;
+
+    let side = (cells.len() as f64).sqrt().round() as usize;
+    if side * side != cells.len() {
+        return Err(format!(
+            "Expected a perfect-square number of cells, got {}",
+            cells.len()
+        ));
+    }
+    Sudoku::box_size_for_side(side)?;
+
+    if let Some(bad) = cells.iter().flatten().find(|&&v| v as usize > side) {
+        return Err(format!("Value {} out of range 1-{}", bad, side));
+    }
+
+    let preset = cells.chunks(side).map(|row| row.to_vec()).collect();
+    Ok(Sudoku::from_preset(preset))
+}
+
+/// Parses the ksudoku-style puzzle string: `WxH:type:v,v,v,...`, where
+/// `W`/`H` both give the board's side length, `type` is a one-letter
+/// variant tag (accepted but ignored -- this entry point only builds
+/// classic boards), and the values are `side*side` comma-separated
+/// digits in row-major order, `0` meaning empty.
+pub fn from_ksudoku(s: &str) -> Result<Sudoku, String> {
+    let trimmed = s.trim();
+    let mut parts = trimmed.splitn(3, ':');
+    let dims = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| "Missing dimensions field".to_string())?;
+    parts
+        .next()
+        .ok_or_else(|| "Missing puzzle type field".to_string())?;
+    let values = parts
+        .next()
+        .ok_or_else(|| "Missing comma-separated values field".to_string())?;
+
+    let (w, h) = dims
+        .split_once('x')
+        .ok_or_else(|| format!("Invalid dimensions '{}': expected 'WxH'", dims))?;
+    let width: usize = w
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid width '{}' in dimensions '{}'", w, dims))?;
+    let height: usize = h
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid height '{}' in dimensions '{}'", h, dims))?;
+    if width != height {
+        return Err(format!(
+            "Non-square board {}x{} is not supported",
+            width, height
+        ));
+    }
+    let side = width;
+    Sudoku::box_size_for_side(side)?;
+
+    let cells: Vec<u8> = values
+        .split(',')
+        .map(|tok| {
+            tok.trim()
+                .parse::<u8>()
+                .map_err(|_| format!("Invalid cell value '{}'", tok))
+        })
+        .collect::<Result<_, _>>()?;
+
+    if cells.len() != side * side {
+        return Err(format!(
+            "Expected {} values for a {}x{} board, got {}",
+            side * side,
+            side,
+            side,
+            cells.len()
+        ));
+    }
+    if let Some(&bad) = cells.iter().find(|&v| *v as usize > side) {
+        return Err(format!("Value {} out of range 0-{}", bad, side));
+    }
+
+    let preset: Vec<Vec<Option<u8>>> = cells
+        .chunks(side)
+        .map(|row| {
+            row.iter()
+                .map(|&v| if v == 0 { None } else { Some(v) })
+                .collect()
+        })
+        .collect();
+
+    Ok(Sudoku::from_preset(preset))
+}
+
+/// Flat single-line rendering -- the same shape `from_str_line` parses,
+/// `.` for empty cells.
+pub fn to_line_string(sudoku: &Sudoku) -> String {
+    sudoku.to_string()
+}
+
+/// Pretty box-divider grid rendering, the same layout as `Sudoku`'s
+/// `Display` impl, captured as an owned `String`.
+pub fn to_grid_display(sudoku: &Sudoku) -> String {
+    format!("{}", sudoku)
+}
@@ -0,0 +1,234 @@
+//! Auto-detecting parser for the handful of plain-text Sudoku encodings
+//! found in the wild: a flat run of `side*side` characters, one line per
+//! row, and the box-divider "pretty" grid (the same shape `Sudoku`'s own
+//! `Display` impl produces). `parse_puzzle` tries each format's nom
+//! combinator in turn and reports which one matched, so callers that don't
+//! know their input's encoding up front (a mixed puzzle corpus, say) don't
+//! have to pre-convert it.
+
+use std::fmt;
+
+use nom::IResult;
+use nom::branch::alt;
+use nom::character::complete::{char, digit1, line_ending, one_of, space0, space1};
+use nom::combinator::{all_consuming, map_opt, value};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::delimited;
+
+use crate::core::sudoku::Sudoku;
+
+/// Which plain-text encoding a puzzle was successfully parsed as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PuzzleFormat {
+    /// A single run of `side * side` cell characters, no separators.
+    FlatString,
+    /// One line per row, cells separated by whitespace and/or commas.
+    LineGrid,
+    /// A `Display`-style grid with `-`/`+`/`|` box dividers.
+    BoxDividerGrid,
+}
+
+impl fmt::Display for PuzzleFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PuzzleFormat::FlatString => "flat string",
+            PuzzleFormat::LineGrid => "line grid",
+            PuzzleFormat::BoxDividerGrid => "box-divider grid",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// Converts a single cell character into its 1-based digit value ('.' and
+// '0' mean blank), mirroring `Sudoku::from_string`'s handling of '1'-'9'
+// and 'A'.. for orders above 9. Doesn't know the board's side, so callers
+// still need to check the result against it. Shared with `core::format`'s
+// flat-line parser, which uses the same cell alphabet.
+pub(crate) fn char_to_cell(ch: char) -> Option<Option<u8>> {
+    match ch {
+        '.' | '0' => Some(None),
+        c if c.is_ascii_digit() => Some(Some(c.to_digit(10).unwrap() as u8)),
+        c if c.is_ascii_alphabetic() => Some(Some((c.to_ascii_uppercase() as u8 - b'A') + 10)),
+        _ => None,
+    }
+}
+
+// A single-character cell token, used by the flat format where there are no
+// separators between cells so a multi-digit number would be ambiguous.
+fn flat_cell(input: &str) -> IResult<&str, Option<u8>> {
+    map_opt(
+        one_of("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz."),
+        char_to_cell,
+    )(input)
+}
+
+// Same as `flat_cell`, but also accepts a bare space as a blank marker, for
+// flat dumps that pad every cell to a fixed width with space rather than
+// '.'/'0'.
+fn flat_cell_with_space_blank(input: &str) -> IResult<&str, Option<u8>> {
+    alt((value(None, char(' ')), flat_cell))(input)
+}
+
+// A cell token for separator-delimited formats: either a run of decimal
+// digits (so 16x16+ boards can use plain numbers like "16"), a single
+// letter (value 10+), or one of the blank markers '.'/'_'.
+fn token_cell(input: &str) -> IResult<&str, Option<u8>> {
+    alt((
+        value(None, alt((char('.'), char('_')))),
+        map_opt(digit1, |digits: &str| {
+            digits
+                .parse::<u8>()
+                .ok()
+                .map(|v| if v == 0 { None } else { Some(v) })
+        }),
+        map_opt(
+            one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"),
+            char_to_cell,
+        ),
+    ))(input)
+}
+
+// Whitespace embedded in an otherwise-flat string is just visual padding,
+// not a blank marker, so it's stripped before the character-by-character
+// parse (matching the plain `.`/`0` flat encoding most puzzle dumps use).
+fn flat_string_stripped(input: &str) -> IResult<&str, Vec<Option<u8>>> {
+    all_consuming(many1(flat_cell))(input)
+}
+
+// For flat dumps that use space itself as the blank marker (so stripping
+// whitespace would be wrong), parsed against the untouched input.
+fn flat_string_with_space_blank(input: &str) -> IResult<&str, Vec<Option<u8>>> {
+    all_consuming(many1(flat_cell_with_space_blank))(input)
+}
+
+// Whitespace and/or a comma between cells on a line-grid row.
+fn line_cell_separator(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        many1(alt((space1, nom::combinator::recognize(char(','))))),
+    )(input)
+}
+
+fn grid_row(input: &str) -> IResult<&str, Vec<Option<u8>>> {
+    delimited(space0, separated_list1(line_cell_separator, token_cell), space0)(input)
+}
+
+fn line_grid(input: &str) -> IResult<&str, Vec<Vec<Option<u8>>>> {
+    all_consuming(separated_list1(line_ending, grid_row))(input)
+}
+
+// Whitespace, a comma, and/or a box-divider pipe between cells.
+fn box_cell_separator(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        many1(alt((space1, nom::combinator::recognize(one_of(",|"))))),
+    )(input)
+}
+
+fn box_row(input: &str) -> IResult<&str, Vec<Option<u8>>> {
+    delimited(space0, separated_list1(box_cell_separator, token_cell), space0)(input)
+}
+
+// A horizontal box-divider line is made up entirely of dashes, plus signs,
+// equals signs, and whitespace (e.g. "------+-------+------").
+fn is_divider_line(line: &str) -> bool {
+    !line.trim().is_empty() && line.chars().all(|c| "-+=".contains(c) || c.is_whitespace())
+}
+
+fn box_divider_grid(input: &str) -> IResult<&str, Vec<Vec<Option<u8>>>> {
+    let content_lines: Vec<&str> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !is_divider_line(line))
+        .collect();
+
+    if content_lines.is_empty() {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Eof,
+        )));
+    }
+
+    let mut rows = Vec::with_capacity(content_lines.len());
+    for line in &content_lines {
+        let (_, row) = all_consuming(box_row)(line).map_err(|_| {
+            nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Verify))
+        })?;
+        rows.push(row);
+    }
+
+    Ok(("", rows))
+}
+
+// A side length is only valid for a square-box board (9, 16, 25, ...)
+// that still fits in a `CandidateMask`; delegates to `box_size_for_side`
+// so the bound lives in one place instead of being duplicated here.
+fn is_valid_side(side: usize) -> bool {
+    Sudoku::box_size_for_side(side).is_ok()
+}
+
+// Every hint must be a value between 1 and `side`; `from_preset` panics on
+// out-of-range hints rather than erroring, so this has to be checked first.
+fn values_in_range<'a>(cells: impl IntoIterator<Item = &'a Option<u8>>, side: usize) -> bool {
+    cells
+        .into_iter()
+        .all(|cell| cell.is_none_or(|v| v >= 1 && v as usize <= side))
+}
+
+fn reshape_flat(cells: Vec<Option<u8>>) -> Option<Vec<Vec<Option<u8>>>> {
+    let side = (cells.len() as f64).sqrt().round() as usize;
+    if side * side != cells.len() || !is_valid_side(side) || !values_in_range(&cells, side) {
+        return None;
+    }
+
+    Some(cells.chunks(side).map(|row| row.to_vec()).collect())
+}
+
+fn is_square_grid(rows: &[Vec<Option<u8>>]) -> bool {
+    let side = rows.len();
+    is_valid_side(side)
+        && rows.iter().all(|row| row.len() == side)
+        && values_in_range(rows.iter().flatten(), side)
+}
+
+/// Tries every supported puzzle format in turn and returns the `Sudoku`
+/// together with whichever format matched. On failure, the error lists
+/// every format that was attempted.
+pub fn parse_puzzle(input: &str) -> Result<(Sudoku, PuzzleFormat), String> {
+    let trimmed = input.trim();
+    let stripped: String = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+
+    // Tried before the flat formats: a grid of single-character cells
+    // separated by whitespace would also read as a flat string once its
+    // whitespace is stripped.
+    if let Ok((_, rows)) = line_grid(trimmed) {
+        if is_square_grid(&rows) {
+            return Ok((Sudoku::from_preset(rows), PuzzleFormat::LineGrid));
+        }
+    }
+
+    if let Ok((_, cells)) = flat_string_stripped(&stripped) {
+        if let Some(preset) = reshape_flat(cells) {
+            return Ok((Sudoku::from_preset(preset), PuzzleFormat::FlatString));
+        }
+    }
+
+    if let Ok((_, cells)) = flat_string_with_space_blank(trimmed) {
+        if let Some(preset) = reshape_flat(cells) {
+            return Ok((Sudoku::from_preset(preset), PuzzleFormat::FlatString));
+        }
+    }
+
+    if let Ok((_, rows)) = box_divider_grid(trimmed) {
+        if is_square_grid(&rows) {
+            return Ok((Sudoku::from_preset(rows), PuzzleFormat::BoxDividerGrid));
+        }
+    }
+
+    Err(format!(
+        "Could not parse puzzle as any known format (tried: {}, {}, {})",
+        PuzzleFormat::FlatString,
+        PuzzleFormat::LineGrid,
+        PuzzleFormat::BoxDividerGrid,
+    ))
+}
@@ -1,7 +1,14 @@
+use crate::core::solvers::constraints::Constraint;
 use crate::core::sudoku::Sudoku;
 use rand::seq::SliceRandom;
 use std::time::{Duration, Instant};
 
+// The search engine below packs row/column/box occupancy into `u32` masks
+// (bit `num` marking digit `num` placed), so it only supports boards with
+// side <= 31 -- comfortably covering every ORDER^2 board up to 25x25
+// (box_size 5), matching `Sudoku`'s own `CandidateMask` width. Larger
+// orders would need a wider mask (u64 or a bitset) on both sides.
+
 #[derive(Debug, Clone)]
 pub struct SolverStats {
     pub solutions_found: usize,
@@ -10,11 +17,13 @@ pub struct SolverStats {
     pub nodes_explored: usize,
     pub backtracks: usize,
     pub leaves: usize,
-    pub tree_width_by_level: [usize; 81],
+    pub tree_width_by_level: Vec<usize>, // stores width at each depth level (index), sized side*side
+    pub assignments_by_propagation: usize,
+    pub assignments_by_search: usize,
 }
 
-impl Default for SolverStats {
-    fn default() -> Self {
+impl SolverStats {
+    fn new(side: usize) -> Self {
         Self {
             solutions_found: 0,
             search_duration: Duration::default(),
@@ -22,12 +31,12 @@ impl Default for SolverStats {
             nodes_explored: 0,
             backtracks: 0,
             leaves: 0,
-            tree_width_by_level: [0; 81],
+            tree_width_by_level: vec![0; side * side],
+            assignments_by_propagation: 0,
+            assignments_by_search: 0,
         }
     }
-}
 
-impl SolverStats {
     /// Print comprehensive analysis of solver performance and search tree
     pub fn print_analysis(&self) {
         println!("=== Sudoku Search Tree Analysis ===");
@@ -65,11 +74,11 @@ impl SolverStats {
         );
         println!("  Maximum recursion depth: {}", self.max_recursion_depth);
         println!("  Backtracks: {}", self.backtracks);
-        // println!("  Branching levels: {}", self.branching_levels_count());
-        // println!(
-        //     "  Avg branching factor: {:.2}",
-        //     self.average_branching_factor()
-        // );
+        println!(
+            "  Assignments by propagation: {}",
+            self.assignments_by_propagation
+        );
+        println!("  Assignments by search: {}", self.assignments_by_search);
 
         self.print_tree_bar_chart();
 
@@ -199,25 +208,27 @@ impl SolverStats {
 
 #[derive(Debug, Clone)]
 pub enum SearchStrategy {
-    Default,      // Left-right, top-down (0,0) to (8,8)
+    Default,      // Left-right, top-down (0,0) to (side-1,side-1)
     RowColRandom, // Random row and column ordering
     CellRandom,   // Random cell ordering (your new approach)
     CustomRowCol {
-        // Custom row/column ordering
-        row_order: [usize; 9],
-        col_order: [usize; 9],
+        // Custom row/column ordering, one entry per row/column of the board
+        row_order: Vec<usize>,
+        col_order: Vec<usize>,
     },
     CustomCell {
         // Custom cell ordering
         cell_order: Vec<(usize, usize)>,
     },
+    Mrv, // Minimum-remaining-values: always branch on the emptiest cell
+    Propagate, // Naked/hidden singles fixpoint before each MRV branch
 }
 
 pub fn generate_cell_order_from_row_col(
-    row_order: &[usize; 9],
-    col_order: &[usize; 9],
+    row_order: &[usize],
+    col_order: &[usize],
 ) -> Vec<(usize, usize)> {
-    let mut cells = Vec::with_capacity(81);
+    let mut cells = Vec::with_capacity(row_order.len() * col_order.len());
     for &r in row_order {
         for &c in col_order {
             cells.push((r, c));
@@ -238,12 +249,12 @@ pub fn find_one_solution_rand_cell_order(sudoku: &Sudoku) -> (Option<Sudoku>, So
 }
 pub fn find_one_solution_custom_rowcol_order(
     sudoku: &Sudoku,
-    row_order: [usize; 9],
-    col_order: [usize; 9],
+    row_order: Vec<usize>,
+    col_order: Vec<usize>,
 ) -> (Option<Sudoku>, SolverStats) {
     find_one_solution_strategy(
-        sudoku, 
-        SearchStrategy::CustomRowCol { row_order, col_order }
+        sudoku,
+        SearchStrategy::CustomRowCol { row_order, col_order },
     )
 }
 pub fn find_one_solution_custom_cell_order(
@@ -251,10 +262,230 @@ pub fn find_one_solution_custom_cell_order(
     cell_order: &[(usize, usize)],
 ) -> (Option<Sudoku>, SolverStats) {
     find_one_solution_strategy(
-        sudoku, 
+        sudoku,
         SearchStrategy::CustomCell { cell_order: cell_order.to_vec() }
     )
 }
+// Same as `find_one_solution_rand_rowcol_order`, but draws its shuffle
+// from a `StdRng` seeded with `seed` instead of the thread-local RNG, so
+// callers that fan work out across threads (e.g. the rand_ord_experiment
+// binary) can reproduce a given run deterministically.
+pub fn find_one_solution_rand_rowcol_order_seeded(
+    sudoku: &Sudoku,
+    seed: u64,
+) -> (Option<Sudoku>, SolverStats) {
+    use rand::SeedableRng;
+
+    let start_time = Instant::now();
+    let side = sudoku.side();
+    let mut stats = SolverStats::new(side);
+    let mut solutions = Vec::new();
+
+    let mut board = vec![vec![0u8; side]; side];
+    let (mut rows, mut cols, mut subgrids) = (vec![0u32; side], vec![0u32; side], vec![0u32; side]);
+    initialize_from_sudoku(sudoku, &mut board, &mut rows, &mut cols, &mut subgrids);
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    let mut row_arr: Vec<usize> = (0..side).collect();
+    let mut col_arr: Vec<usize> = (0..side).collect();
+    row_arr.shuffle(&mut rng);
+    col_arr.shuffle(&mut rng);
+    let cell_order = generate_cell_order_from_row_col(&row_arr, &col_arr);
+
+    solve_recursive_cell_order(
+        sudoku.box_size,
+        &mut board,
+        &mut rows,
+        &mut cols,
+        &mut subgrids,
+        &cell_order,
+        0,
+        0,
+        &mut stats,
+        &mut solutions,
+        false,
+        None,
+    );
+
+    let solution = solutions.into_iter().next();
+    stats.solutions_found = if solution.is_some() { 1 } else { 0 };
+    stats.search_duration = start_time.elapsed();
+    (solution, stats)
+}
+
+// Same as `find_one_solution_rand_cell_order`, but draws its shuffle from
+// a `StdRng` seeded with `seed` instead of the thread-local RNG, for the
+// same reproducibility reason as `find_one_solution_rand_rowcol_order_seeded`.
+pub fn find_one_solution_rand_cell_order_seeded(
+    sudoku: &Sudoku,
+    seed: u64,
+) -> (Option<Sudoku>, SolverStats) {
+    use rand::SeedableRng;
+
+    let start_time = Instant::now();
+    let side = sudoku.side();
+    let mut stats = SolverStats::new(side);
+    let mut solutions = Vec::new();
+
+    let mut board = vec![vec![0u8; side]; side];
+    let (mut rows, mut cols, mut subgrids) = (vec![0u32; side], vec![0u32; side], vec![0u32; side]);
+    initialize_from_sudoku(sudoku, &mut board, &mut rows, &mut cols, &mut subgrids);
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    let mut cell_order: Vec<(usize, usize)> = Vec::with_capacity(side * side);
+    for i in 0..side {
+        for j in 0..side {
+            cell_order.push((i, j));
+        }
+    }
+    cell_order.shuffle(&mut rng);
+
+    solve_recursive_cell_order(
+        sudoku.box_size,
+        &mut board,
+        &mut rows,
+        &mut cols,
+        &mut subgrids,
+        &cell_order,
+        0,
+        0,
+        &mut stats,
+        &mut solutions,
+        false,
+        None,
+    );
+
+    let solution = solutions.into_iter().next();
+    stats.solutions_found = if solution.is_some() { 1 } else { 0 };
+    stats.search_duration = start_time.elapsed();
+    (solution, stats)
+}
+
+// Counts solutions to `sudoku`, stopping as soon as `limit` are found.
+// Used for puzzle validation (`has_unique_solution`) and generation
+// (dig-and-check: keep removing clues while the remaining puzzle still
+// has exactly one solution).
+pub fn count_solutions_upto(sudoku: &Sudoku, limit: usize) -> (usize, SolverStats) {
+    let start_time = Instant::now();
+    let side = sudoku.side();
+    let mut stats = SolverStats::new(side);
+    let mut solutions = Vec::new();
+
+    let mut board = vec![vec![0u8; side]; side];
+    let (mut rows, mut cols, mut subgrids) = (vec![0u32; side], vec![0u32; side], vec![0u32; side]);
+    initialize_from_sudoku(sudoku, &mut board, &mut rows, &mut cols, &mut subgrids);
+
+    let order: Vec<usize> = (0..side).collect();
+    let cell_order = generate_cell_order_from_row_col(&order, &order);
+
+    solve_recursive_cell_order(
+        sudoku.box_size,
+        &mut board,
+        &mut rows,
+        &mut cols,
+        &mut subgrids,
+        &cell_order,
+        0,
+        0,
+        &mut stats,
+        &mut solutions,
+        true,
+        Some(limit),
+    );
+
+    stats.solutions_found = solutions.len();
+    stats.search_duration = start_time.elapsed();
+    (solutions.len(), stats)
+}
+
+// A puzzle is well-formed if it has exactly one solution.
+pub fn has_unique_solution(sudoku: &Sudoku) -> bool {
+    count_solutions_upto(sudoku, 2).0 == 1
+}
+
+// Enumerates every solution to `sudoku` in `strategy`'s cell order,
+// stopping early once `max` are found (or running to exhaustion if `max`
+// is `None`). Unlike `find_one_solution_strategy`, the search keeps going
+// past the first hit, and `stats.tree_width_by_level`/`nodes_explored`
+// accumulate across the whole enumeration rather than resetting per
+// solution.
+//
+// `Mrv`/`Propagate` don't have a find-all-solutions variant of their
+// recursive search, so -- like every other non-fast-path strategy in
+// `find_one_solution_with_constraints` -- they fall back to `Default`'s
+// left-right, top-down ordering here.
+pub fn find_all_solutions(
+    sudoku: &Sudoku,
+    strategy: SearchStrategy,
+    max: Option<usize>,
+) -> (Vec<Sudoku>, SolverStats) {
+    let start_time = Instant::now();
+    let side = sudoku.side();
+    let box_size = sudoku.box_size;
+    let mut stats = SolverStats::new(side);
+    let mut solutions = Vec::new();
+
+    if max == Some(0) {
+        stats.search_duration = start_time.elapsed();
+        return (solutions, stats);
+    }
+
+    let mut board = vec![vec![0u8; side]; side];
+    let (mut rows, mut cols, mut subgrids) = (vec![0u32; side], vec![0u32; side], vec![0u32; side]);
+    initialize_from_sudoku(sudoku, &mut board, &mut rows, &mut cols, &mut subgrids);
+
+    let cell_order: Vec<(usize, usize)> = match strategy {
+        SearchStrategy::RowColRandom => {
+            let mut row_order: Vec<usize> = (0..side).collect();
+            let mut col_order: Vec<usize> = (0..side).collect();
+            row_order.shuffle(&mut rand::rng());
+            col_order.shuffle(&mut rand::rng());
+            generate_cell_order_from_row_col(&row_order, &col_order)
+        }
+        SearchStrategy::CellRandom => {
+            let mut cells: Vec<(usize, usize)> =
+                (0..side).flat_map(|i| (0..side).map(move |j| (i, j))).collect();
+            cells.shuffle(&mut rand::rng());
+            cells
+        }
+        SearchStrategy::CustomRowCol { row_order, col_order } => {
+            generate_cell_order_from_row_col(&row_order, &col_order)
+        }
+        SearchStrategy::CustomCell { cell_order } => cell_order,
+        // Default, plus Mrv/Propagate (no find-all variant of their own
+        // recursive search), all use the plain left-right, top-down order.
+        _ => {
+            let order: Vec<usize> = (0..side).collect();
+            generate_cell_order_from_row_col(&order, &order)
+        }
+    };
+
+    solve_recursive_cell_order(
+        box_size,
+        &mut board,
+        &mut rows,
+        &mut cols,
+        &mut subgrids,
+        &cell_order,
+        0,
+        0,
+        &mut stats,
+        &mut solutions,
+        true,
+        max,
+    );
+
+    stats.solutions_found = solutions.len();
+    stats.search_duration = start_time.elapsed();
+    (solutions, stats)
+}
+
+pub fn find_one_solution_mrv_order(sudoku: &Sudoku) -> (Option<Sudoku>, SolverStats) {
+    find_one_solution_strategy(sudoku, SearchStrategy::Mrv)
+}
+pub fn find_one_solution_propagate(sudoku: &Sudoku) -> (Option<Sudoku>, SolverStats) {
+    find_one_solution_strategy(sudoku, SearchStrategy::Propagate)
+}
 
 pub fn find_one_solution_strategy(
     sudoku: &Sudoku,
@@ -262,36 +493,76 @@ pub fn find_one_solution_strategy(
 ) -> (Option<Sudoku>, SolverStats) {
     // Initialize stat recorders and solutions vec
     let start_time = Instant::now();
-    let mut stats = SolverStats::default();
+    let side = sudoku.side();
+    let box_size = sudoku.box_size;
+    let mut stats = SolverStats::new(side);
     let mut solutions = Vec::new();
 
-    // Instantiates board, row, col, and subgrid data structures
-    let mut board = [[0u8; 9]; 9];
-    let (mut rows, mut cols, mut subgrids) = ([0u16; 9], [0u16; 9], [0u16; 9]);
+    // Instantiates board, row, col, and subgrid data structures, sized to
+    // the puzzle's own order (e.g. side=16 for a 4x4-box board)
+    let mut board = vec![vec![0u8; side]; side];
+    let (mut rows, mut cols, mut subgrids) = (vec![0u32; side], vec![0u32; side], vec![0u32; side]);
 
     // Initializes from original puzzle and it is read-only
     initialize_from_sudoku(sudoku, &mut board, &mut rows, &mut cols, &mut subgrids);
 
+    if matches!(strategy, SearchStrategy::Mrv) {
+        solve_recursive_mrv(
+            box_size,
+            &mut board,
+            &mut rows,
+            &mut cols,
+            &mut subgrids,
+            0,
+            &mut stats,
+            &mut solutions,
+            false,
+        );
+
+        let solution = solutions.into_iter().next();
+
+        stats.solutions_found = if solution.is_some() { 1 } else { 0 };
+        stats.search_duration = start_time.elapsed();
+        return (solution, stats);
+    }
+
+    if matches!(strategy, SearchStrategy::Propagate) {
+        solve_recursive_propagate(
+            box_size,
+            &mut board,
+            &mut rows,
+            &mut cols,
+            &mut subgrids,
+            0,
+            &mut stats,
+            &mut solutions,
+            false,
+        );
+
+        let solution = solutions.into_iter().next();
+
+        stats.solutions_found = if solution.is_some() { 1 } else { 0 };
+        stats.search_duration = start_time.elapsed();
+        return (solution, stats);
+    }
+
     // Generate cell order based on strategy
     let cell_order = match strategy {
-        SearchStrategy::Default => generate_cell_order_from_row_col(
-            &[0, 1, 2, 3, 4, 5, 6, 7, 8],
-            &[0, 1, 2, 3, 4, 5, 6, 7, 8],
-        ),
+        SearchStrategy::Default => {
+            let order: Vec<usize> = (0..side).collect();
+            generate_cell_order_from_row_col(&order, &order)
+        }
         SearchStrategy::RowColRandom => {
-            let mut row_arr = [0, 1, 2, 3, 4, 5, 6, 7, 8];
-            let mut col_arr = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+            let mut row_arr: Vec<usize> = (0..side).collect();
+            let mut col_arr: Vec<usize> = (0..side).collect();
             row_arr.shuffle(&mut rand::rng());
             col_arr.shuffle(&mut rand::rng());
             generate_cell_order_from_row_col(&row_arr, &col_arr)
         }
         SearchStrategy::CellRandom => {
-            let mut cells: Vec<(usize, usize)> = Vec::with_capacity(81);
-            for i in 0..9 {
-                for j in 0..9 {
-                    cells.push((i, j));
-                }
-            }
+            let mut cells: Vec<(usize, usize)> = (0..side)
+                .flat_map(|i| (0..side).map(move |j| (i, j)))
+                .collect();
             cells.shuffle(&mut rand::rng());
             cells
         }
@@ -300,10 +571,13 @@ pub fn find_one_solution_strategy(
             col_order,
         } => generate_cell_order_from_row_col(&row_order, &col_order),
         SearchStrategy::CustomCell { cell_order } => cell_order,
+        SearchStrategy::Mrv => unreachable!("handled above before cell order is generated"),
+        SearchStrategy::Propagate => unreachable!("handled above before cell order is generated"),
     };
 
     // Call the unified recursive solver with cell order
     solve_recursive_cell_order(
+        box_size,
         &mut board,
         &mut rows,
         &mut cols,
@@ -314,6 +588,7 @@ pub fn find_one_solution_strategy(
         &mut stats,
         &mut solutions,
         false,
+        None,
     );
 
     let solution = solutions.into_iter().next();
@@ -323,79 +598,50 @@ pub fn find_one_solution_strategy(
     (solution, stats)
 }
 
-// Finds all solutions to a Sudoku puzzle
-// pub fn find_all_solutions(sudoku: &Sudoku) -> (Vec<Sudoku>, SolverStats) {
-//     // Initialize stat recorders and solutions vec
-//     let start_time = Instant::now();
-//     let mut stats = SolverStats::default();
-//     let mut solutions = Vec::new();
-
-//     // Instantiates board, row, col, and subgrid data structures
-//     let mut board = [[0u8; 9]; 9];
-//     let (mut rows, mut cols, mut subgrids) = ([0u16; 9], [0u16; 9], [0u16; 9]);
-
-//     // Initializes from original puzzle and it is read-only
-//     initialize_from_sudoku(sudoku, &mut board, &mut rows, &mut cols, &mut subgrids);
-
-//     let row_order: [usize; 9] = [0, 1, 2, 3, 4, 5, 6, 7, 8];
-//     let col_order: [usize; 9] = [0, 1, 2, 3, 4, 5, 6, 7, 8];
-
-//     solve_recursive(
-//         &mut board,
-//         &mut rows,
-//         &mut cols,
-//         &mut subgrids,
-//         &row_order,
-//         &col_order,
-//         0,
-//         0,
-//         0,
-//         &mut stats,
-//         &mut solutions,
-//         true,
-//     );
-
-//     stats.solutions_found = solutions.len();
-//     stats.search_duration = start_time.elapsed();
-//     (solutions, stats)
-// }
-
 // Initializes board, row, col, and subgrid data structures
 fn initialize_from_sudoku(
     sudoku: &Sudoku,
-    board: &mut [[u8; 9]; 9],
-    rows: &mut [u16; 9],
-    cols: &mut [u16; 9],
-    subgrids: &mut [u16; 9],
+    board: &mut [Vec<u8>],
+    rows: &mut [u32],
+    cols: &mut [u32],
+    subgrids: &mut [u32],
 ) {
-    for i in 0..9 {
-        for j in 0..9 {
+    let side = sudoku.side();
+    let b = sudoku.box_size;
+    for i in 0..side {
+        for j in 0..side {
             if let Some(value) = sudoku.get_solved_value(i, j) {
                 board[i][j] = value;
                 let bit = 1 << value; // bitwise left shift so that the value-th bit is set to 1
                 rows[i] |= bit; // bitwise OR operator updates rows[i] with the information from bit
                 cols[j] |= bit;
-                subgrids[(i / 3) * 3 + j / 3] |= bit;
+                subgrids[(i / b) * b + j / b] |= bit;
             }
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn solve_recursive_cell_order(
-    board: &mut [[u8; 9]; 9],
-    rows: &mut [u16; 9],
-    cols: &mut [u16; 9],
-    subgrids: &mut [u16; 9],
+    box_size: usize,
+    board: &mut [Vec<u8>],
+    rows: &mut [u32],
+    cols: &mut [u32],
+    subgrids: &mut [u32],
     cell_order: &[(usize, usize)],
     cell_idx: usize,
     depth: usize,
     stats: &mut SolverStats,
     solutions: &mut Vec<Sudoku>,
     find_all: bool,
+    cap: Option<usize>,
 ) {
+    let side = board.len();
+    let total_cells = side * side;
+
     // Find next empty cell
     let mut current_idx = cell_idx;
-    while current_idx < 81 {
+    while current_idx < total_cells {
         let (i, j) = cell_order[current_idx];
         if board[i][j] == 0 {
             break;
@@ -404,10 +650,10 @@ fn solve_recursive_cell_order(
     }
 
     // Check if board is filled
-    if current_idx == 81 {
-        let mut solution_sudoku = Sudoku::new();
-        for row in 0..9 {
-            for col in 0..9 {
+    if current_idx == total_cells {
+        let mut solution_sudoku = Sudoku::new_with_box_size(box_size);
+        for row in 0..side {
+            for col in 0..side {
                 solution_sudoku.set_cell(row, col, board[row][col]).unwrap();
             }
         }
@@ -425,20 +671,21 @@ fn solve_recursive_cell_order(
 
     let mut any_valid_moves = false;
 
-    for num in 1..=9 {
-        if is_safe(rows, cols, subgrids, i, j, num) {
+    for num in 1..=(side as u8) {
+        if is_safe(box_size, rows, cols, subgrids, i, j, num) {
             any_valid_moves = true;
 
             // Place number
             board[i][j] = num;
 
-            // Update the u16 bits in each row, col, and subgrid
+            // Update the bits in each row, col, and subgrid
             let bit = 1 << num;
             rows[i] |= bit;
             cols[j] |= bit;
-            subgrids[(i / 3) * 3 + j / 3] |= bit;
+            subgrids[(i / box_size) * box_size + j / box_size] |= bit;
 
             solve_recursive_cell_order(
+                box_size,
                 board,
                 rows,
                 cols,
@@ -449,18 +696,25 @@ fn solve_recursive_cell_order(
                 stats,
                 solutions,
                 find_all,
+                cap,
             );
 
             // If we found at least one solution and we're not finding all, early return
             if !solutions.is_empty() && !find_all {
                 return;
             }
+            // If we're finding all solutions but have hit the cap, stop early too
+            if let Some(limit) = cap {
+                if solutions.len() >= limit {
+                    return;
+                }
+            }
 
             // Backtrack
             board[i][j] = 0; // Set current cell to 0
             rows[i] &= !bit; // Flips the num-th bit (current cell) to 0
             cols[j] &= !bit;
-            subgrids[(i / 3) * 3 + j / 3] &= !bit;
+            subgrids[(i / box_size) * box_size + j / box_size] &= !bit;
             stats.backtracks += 1;
         }
     }
@@ -471,17 +725,348 @@ fn solve_recursive_cell_order(
     }
 }
 
+// Scans every still-empty cell and returns the one with the fewest
+// remaining candidates, along with its candidate bitmask (bit `num` set
+// means `num` is still legal there). Ties break by scan order. Returns
+// `None` once the board is completely filled.
+fn select_mrv_cell(
+    box_size: usize,
+    board: &[Vec<u8>],
+    rows: &[u32],
+    cols: &[u32],
+    subgrids: &[u32],
+) -> Option<(usize, usize, u32)> {
+    let side = board.len();
+    let full_mask: u32 = if side >= 31 { u32::MAX } else { (1 << (side + 1)) - 2 };
+    let mut best: Option<(usize, usize, u32, u32)> = None;
+
+    for i in 0..side {
+        for j in 0..side {
+            if board[i][j] != 0 {
+                continue;
+            }
+
+            let used = rows[i] | cols[j] | subgrids[(i / box_size) * box_size + j / box_size];
+            let candidates = !used & full_mask;
+            let count = candidates.count_ones();
+
+            if count == 0 {
+                // Dead end: this cell has no legal value left.
+                return Some((i, j, 0));
+            }
+
+            let is_better = match best {
+                Some((_, _, _, best_count)) => count < best_count,
+                None => true,
+            };
+            if is_better {
+                best = Some((i, j, candidates, count));
+            }
+        }
+    }
+
+    best.map(|(i, j, candidates, _)| (i, j, candidates))
+}
+
+// MRV (minimum-remaining-values) variant of `solve_recursive_cell_order`:
+// instead of walking a precomputed cell order, it re-scans the board at
+// every node and branches on whichever empty cell has the fewest
+// candidates, pruning immediately if one has none left.
+#[allow(clippy::too_many_arguments)]
+fn solve_recursive_mrv(
+    box_size: usize,
+    board: &mut [Vec<u8>],
+    rows: &mut [u32],
+    cols: &mut [u32],
+    subgrids: &mut [u32],
+    depth: usize,
+    stats: &mut SolverStats,
+    solutions: &mut Vec<Sudoku>,
+    find_all: bool,
+) {
+    let side = board.len();
+
+    let (i, j, candidates) = match select_mrv_cell(box_size, board, rows, cols, subgrids) {
+        Some(cell) => cell,
+        None => {
+            // Board is filled
+            let mut solution_sudoku = Sudoku::new_with_box_size(box_size);
+            for row in 0..side {
+                for col in 0..side {
+                    solution_sudoku.set_cell(row, col, board[row][col]).unwrap();
+                }
+            }
+            solutions.push(solution_sudoku);
+            stats.leaves += 1;
+            return;
+        }
+    };
+
+    stats.nodes_explored += 1;
+    stats.max_recursion_depth = stats.max_recursion_depth.max(depth);
+    stats.tree_width_by_level[depth] += 1;
+
+    let mut any_valid_moves = false;
+
+    for num in 1..=(side as u8) {
+        let bit = 1 << num;
+        if candidates & bit == 0 {
+            continue;
+        }
+        any_valid_moves = true;
+
+        board[i][j] = num;
+        rows[i] |= bit;
+        cols[j] |= bit;
+        subgrids[(i / box_size) * box_size + j / box_size] |= bit;
+
+        solve_recursive_mrv(box_size, board, rows, cols, subgrids, depth + 1, stats, solutions, find_all);
+
+        if !solutions.is_empty() && !find_all {
+            return;
+        }
+
+        board[i][j] = 0;
+        rows[i] &= !bit;
+        cols[j] &= !bit;
+        subgrids[(i / box_size) * box_size + j / box_size] &= !bit;
+        stats.backtracks += 1;
+    }
+
+    if !any_valid_moves {
+        // Dead-end leaf: the MRV cell had zero candidates.
+        stats.leaves += 1;
+    }
+}
+
+// Every row, column, and box, as cell lists — the 3*side "units" that
+// must each contain every digit exactly once.
+fn units_for_side(box_size: usize) -> Vec<Vec<(usize, usize)>> {
+    let side = box_size * box_size;
+    let mut units = Vec::with_capacity(3 * side);
+    for r in 0..side {
+        units.push((0..side).map(|c| (r, c)).collect());
+    }
+    for c in 0..side {
+        units.push((0..side).map(|r| (r, c)).collect());
+    }
+    for box_row in 0..box_size {
+        for box_col in 0..box_size {
+            units.push(
+                (0..box_size)
+                    .flat_map(|dr| (0..box_size).map(move |dc| (dr, dc)))
+                    .map(|(dr, dc)| (box_row * box_size + dr, box_col * box_size + dc))
+                    .collect(),
+            );
+        }
+    }
+    units
+}
+
+#[allow(clippy::too_many_arguments)]
+fn assign_cell(
+    box_size: usize,
+    board: &mut [Vec<u8>],
+    rows: &mut [u32],
+    cols: &mut [u32],
+    subgrids: &mut [u32],
+    i: usize,
+    j: usize,
+    num: u8,
+) {
+    let bit = 1 << num;
+    board[i][j] = num;
+    rows[i] |= bit;
+    cols[j] |= bit;
+    subgrids[(i / box_size) * box_size + j / box_size] |= bit;
+}
+
+// Runs naked-singles and hidden-singles propagation to a fixpoint,
+// recording every cell it assigns in `assigned` so the caller can undo
+// them on backtrack. Returns `false` the moment a contradiction is found
+// (some empty cell has no legal value left).
+#[allow(clippy::too_many_arguments)]
+fn propagate_fixpoint(
+    box_size: usize,
+    board: &mut [Vec<u8>],
+    rows: &mut [u32],
+    cols: &mut [u32],
+    subgrids: &mut [u32],
+    assigned: &mut Vec<(usize, usize, u8)>,
+    stats: &mut SolverStats,
+) -> bool {
+    let side = board.len();
+    let full_mask: u32 = if side >= 31 { u32::MAX } else { (1 << (side + 1)) - 2 };
+    let units = units_for_side(box_size);
+
+    loop {
+        let mut changed = false;
+
+        // Naked singles: an empty cell with exactly one candidate left.
+        for i in 0..side {
+            for j in 0..side {
+                if board[i][j] != 0 {
+                    continue;
+                }
+                let used = rows[i] | cols[j] | subgrids[(i / box_size) * box_size + j / box_size];
+                let candidates = !used & full_mask;
+                if candidates == 0 {
+                    return false;
+                }
+                if candidates.count_ones() == 1 {
+                    let num = candidates.trailing_zeros() as u8;
+                    assign_cell(box_size, board, rows, cols, subgrids, i, j, num);
+                    assigned.push((i, j, num));
+                    stats.assignments_by_propagation += 1;
+                    changed = true;
+                }
+            }
+        }
+
+        // Hidden singles: a value that only one empty cell in a unit can hold.
+        for unit in &units {
+            for num in 1..=(side as u8) {
+                let bit = 1 << num;
+                let mut only_cell = None;
+                let mut count = 0;
+                for &(r, c) in unit {
+                    if board[r][c] != 0 {
+                        continue;
+                    }
+                    let used = rows[r] | cols[c] | subgrids[(r / box_size) * box_size + c / box_size];
+                    if used & bit == 0 {
+                        count += 1;
+                        only_cell = Some((r, c));
+                        if count > 1 {
+                            break;
+                        }
+                    }
+                }
+                if count == 1 {
+                    let (r, c) = only_cell.unwrap();
+                    assign_cell(box_size, board, rows, cols, subgrids, r, c, num);
+                    assigned.push((r, c, num));
+                    stats.assignments_by_propagation += 1;
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            return true;
+        }
+    }
+}
+
+fn undo_assignments(
+    box_size: usize,
+    board: &mut [Vec<u8>],
+    rows: &mut [u32],
+    cols: &mut [u32],
+    subgrids: &mut [u32],
+    assigned: &[(usize, usize, u8)],
+) {
+    for &(i, j, num) in assigned {
+        let bit = 1 << num;
+        board[i][j] = 0;
+        rows[i] &= !bit;
+        cols[j] &= !bit;
+        subgrids[(i / box_size) * box_size + j / box_size] &= !bit;
+    }
+}
+
+// Propagation-then-branch variant: runs `propagate_fixpoint` at every
+// node, prunes immediately on contradiction, and only branches (via MRV)
+// once propagation has stalled.
+#[allow(clippy::too_many_arguments)]
+fn solve_recursive_propagate(
+    box_size: usize,
+    board: &mut [Vec<u8>],
+    rows: &mut [u32],
+    cols: &mut [u32],
+    subgrids: &mut [u32],
+    depth: usize,
+    stats: &mut SolverStats,
+    solutions: &mut Vec<Sudoku>,
+    find_all: bool,
+) {
+    let side = board.len();
+    let mut assigned = Vec::new();
+
+    if !propagate_fixpoint(box_size, board, rows, cols, subgrids, &mut assigned, stats) {
+        undo_assignments(box_size, board, rows, cols, subgrids, &assigned);
+        stats.leaves += 1;
+        return;
+    }
+
+    let (i, j, candidates) = match select_mrv_cell(box_size, board, rows, cols, subgrids) {
+        Some(cell) => cell,
+        None => {
+            // Board is filled
+            let mut solution_sudoku = Sudoku::new_with_box_size(box_size);
+            for row in 0..side {
+                for col in 0..side {
+                    solution_sudoku.set_cell(row, col, board[row][col]).unwrap();
+                }
+            }
+            solutions.push(solution_sudoku);
+            stats.leaves += 1;
+            undo_assignments(box_size, board, rows, cols, subgrids, &assigned);
+            return;
+        }
+    };
+
+    stats.nodes_explored += 1;
+    stats.max_recursion_depth = stats.max_recursion_depth.max(depth);
+    stats.tree_width_by_level[depth] += 1;
+
+    let mut any_valid_moves = false;
+
+    for num in 1..=(side as u8) {
+        let bit = 1 << num;
+        if candidates & bit == 0 {
+            continue;
+        }
+        any_valid_moves = true;
+
+        board[i][j] = num;
+        rows[i] |= bit;
+        cols[j] |= bit;
+        subgrids[(i / box_size) * box_size + j / box_size] |= bit;
+        stats.assignments_by_search += 1;
+
+        solve_recursive_propagate(box_size, board, rows, cols, subgrids, depth + 1, stats, solutions, find_all);
+
+        if !solutions.is_empty() && !find_all {
+            return;
+        }
+
+        board[i][j] = 0;
+        rows[i] &= !bit;
+        cols[j] &= !bit;
+        subgrids[(i / box_size) * box_size + j / box_size] &= !bit;
+        stats.backtracks += 1;
+    }
+
+    if !any_valid_moves {
+        stats.leaves += 1;
+    }
+
+    undo_assignments(box_size, board, rows, cols, subgrids, &assigned);
+}
+
 pub fn is_safe(
-    rows: &[u16; 9],
-    cols: &[u16; 9],
-    subgrids: &[u16; 9],
+    box_size: usize,
+    rows: &[u32],
+    cols: &[u32],
+    subgrids: &[u32],
     i: usize,
     j: usize,
     num: u8,
 ) -> bool {
     let bit = 1 << num;
     /*
-       bit is a u16 where every bit is 0 except for the bit in the num-th position.
+       bit is a u32 where every bit is 0 except for the bit in the num-th position.
 
        (rows[i] & bit) == 0 checks if the num-th position in rows[i] is 0.
            (rows[i] & bit) is only 0 if the num-th bit in rows[i] is 0.
@@ -489,5 +1074,293 @@ pub fn is_safe(
        If it is, this returns true.
        If not, it returns false, meaning that the cell is not safe.
     */
-    (rows[i] & bit) == 0 && (cols[j] & bit) == 0 && (subgrids[(i / 3) * 3 + j / 3] & bit) == 0
+    (rows[i] & bit) == 0
+        && (cols[j] & bit) == 0
+        && (subgrids[(i / box_size) * box_size + j / box_size] & bit) == 0
+}
+
+// Increments the per-peer refcount/bitmask for `val` after it's placed at
+// (row, col), so a later `forbidden_mask` lookup for any of those peers is
+// an O(1) bit test. Paired with `on_remove_constrained` below.
+fn on_place_constrained(
+    peers: &[Vec<(usize, usize)>],
+    side: usize,
+    row: usize,
+    col: usize,
+    val: u8,
+    forbidden_counts: &mut [Vec<u16>],
+    forbidden_mask: &mut [u32],
+) {
+    let bit = 1u32 << val;
+    for &(r, c) in &peers[row * side + col] {
+        let idx = r * side + c;
+        let count = &mut forbidden_counts[idx][val as usize - 1];
+        if *count == 0 {
+            forbidden_mask[idx] |= bit;
+        }
+        *count += 1;
+    }
+}
+
+// The inverse of `on_place_constrained`, called on backtrack.
+fn on_remove_constrained(
+    peers: &[Vec<(usize, usize)>],
+    side: usize,
+    row: usize,
+    col: usize,
+    val: u8,
+    forbidden_counts: &mut [Vec<u16>],
+    forbidden_mask: &mut [u32],
+) {
+    let bit = 1u32 << val;
+    for &(r, c) in &peers[row * side + col] {
+        let idx = r * side + c;
+        let count = &mut forbidden_counts[idx][val as usize - 1];
+        *count -= 1;
+        if *count == 0 {
+            forbidden_mask[idx] &= !bit;
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn solve_recursive_constrained(
+    board: &mut [Vec<u8>],
+    side: usize,
+    peers: &[Vec<(usize, usize)>],
+    forbidden_counts: &mut [Vec<u16>],
+    forbidden_mask: &mut [u32],
+    cell_order: &[(usize, usize)],
+    cell_idx: usize,
+    depth: usize,
+    stats: &mut SolverStats,
+    solutions: &mut Vec<Vec<Vec<u8>>>,
+    cap: Option<usize>,
+) {
+    let total_cells = side * side;
+    let mut current_idx = cell_idx;
+    while current_idx < total_cells {
+        let (r, c) = cell_order[current_idx];
+        if board[r][c] == 0 {
+            break;
+        }
+        current_idx += 1;
+    }
+
+    if current_idx == total_cells {
+        solutions.push(board.to_vec());
+        stats.leaves += 1;
+        return;
+    }
+
+    let (row, col) = cell_order[current_idx];
+
+    stats.nodes_explored += 1;
+    stats.max_recursion_depth = stats.max_recursion_depth.max(depth);
+    if let Some(slot) = stats.tree_width_by_level.get_mut(depth) {
+        *slot += 1;
+    }
+
+    let mut any_valid_moves = false;
+    let idx = row * side + col;
+
+    for val in 1..=(side as u8) {
+        if forbidden_mask[idx] & (1u32 << val) != 0 {
+            continue;
+        }
+        any_valid_moves = true;
+
+        board[row][col] = val;
+        on_place_constrained(peers, side, row, col, val, forbidden_counts, forbidden_mask);
+
+        solve_recursive_constrained(
+            board,
+            side,
+            peers,
+            forbidden_counts,
+            forbidden_mask,
+            cell_order,
+            current_idx + 1,
+            depth + 1,
+            stats,
+            solutions,
+            cap,
+        );
+
+        if let Some(limit) = cap {
+            if solutions.len() >= limit {
+                return;
+            }
+        } else if !solutions.is_empty() {
+            return; // first solution found; no need to undo before unwinding
+        }
+
+        board[row][col] = 0;
+        on_remove_constrained(peers, side, row, col, val, forbidden_counts, forbidden_mask);
+        stats.backtracks += 1;
+    }
+
+    if !any_valid_moves {
+        stats.leaves += 1;
+    }
+}
+
+/// Solves `sudoku` under an arbitrary `Constraint` instead of the fixed
+/// row/col/box rules `find_one_solution_strategy` hard-codes, so variant
+/// boards (X-sudoku, windoku, anti-knight, ...) can reuse this engine via
+/// `core::solvers::constraints`. `strategy` only controls the initial
+/// cell-visiting order: `Default`/`RowColRandom`/`CellRandom` behave as
+/// they do for the classic solver, while the MRV/propagate/fixed-order
+/// strategies (which depend on the hard-coded fast path) fall back to
+/// `Default`'s ordering here.
+///
+/// `constraint.affected_masks` is queried once per cell up front and
+/// cached, so the search itself only ever does bitmask tests and refcount
+/// updates rather than re-running predicate closures (or, for something
+/// like anti-knight, recomputing its offsets) on every candidate.
+pub fn find_one_solution_with_constraints(
+    sudoku: &Sudoku,
+    strategy: SearchStrategy,
+    constraint: &dyn Constraint,
+) -> (Option<Sudoku>, SolverStats) {
+    let start_time = Instant::now();
+    let side = sudoku.side();
+    let mut stats = SolverStats::new(side);
+
+    let mut board: Vec<Vec<u8>> = vec![vec![0u8; side]; side];
+    for (r, row) in board.iter_mut().enumerate() {
+        for (c, cell) in row.iter_mut().enumerate() {
+            if let Some(value) = sudoku.get_solved_value(r, c) {
+                *cell = value;
+            }
+        }
+    }
+
+    let peers: Vec<Vec<(usize, usize)>> = (0..side)
+        .flat_map(|r| (0..side).map(move |c| (r, c)))
+        .map(|(r, c)| constraint.affected_masks(side, r, c))
+        .collect();
+
+    let mut forbidden_counts: Vec<Vec<u16>> = vec![vec![0u16; side]; side * side];
+    let mut forbidden_mask: Vec<u32> = vec![0u32; side * side];
+    for r in 0..side {
+        for c in 0..side {
+            if board[r][c] != 0 {
+                on_place_constrained(&peers, side, r, c, board[r][c], &mut forbidden_counts, &mut forbidden_mask);
+            }
+        }
+    }
+
+    let cell_order: Vec<(usize, usize)> = match strategy {
+        SearchStrategy::RowColRandom => {
+            let mut row_order: Vec<usize> = (0..side).collect();
+            let mut col_order: Vec<usize> = (0..side).collect();
+            row_order.shuffle(&mut rand::rng());
+            col_order.shuffle(&mut rand::rng());
+            row_order
+                .iter()
+                .flat_map(|&r| col_order.iter().map(move |&c| (r, c)))
+                .collect()
+        }
+        SearchStrategy::CellRandom => {
+            let mut cells: Vec<(usize, usize)> =
+                (0..side).flat_map(|r| (0..side).map(move |c| (r, c))).collect();
+            cells.shuffle(&mut rand::rng());
+            cells
+        }
+        // Default, and everything tied to the hard-coded fast path (MRV,
+        // propagate, fixed custom orderings), fall back to the plain
+        // left-right, top-down order.
+        _ => (0..side).flat_map(|r| (0..side).map(move |c| (r, c))).collect(),
+    };
+
+    let mut solutions: Vec<Vec<Vec<u8>>> = Vec::new();
+    solve_recursive_constrained(
+        &mut board,
+        side,
+        &peers,
+        &mut forbidden_counts,
+        &mut forbidden_mask,
+        &cell_order,
+        0,
+        0,
+        &mut stats,
+        &mut solutions,
+        None,
+    );
+
+    let solution = solutions.into_iter().next().map(|grid| {
+        let mut result = Sudoku::new_with_box_size(sudoku.box_size);
+        for (r, row) in grid.iter().enumerate() {
+            for (c, &val) in row.iter().enumerate() {
+                if val != 0 {
+                    result.set_cell(r, c, val).unwrap();
+                }
+            }
+        }
+        result
+    });
+
+    stats.solutions_found = if solution.is_some() { 1 } else { 0 };
+    stats.search_duration = start_time.elapsed();
+    (solution, stats)
+}
+
+// Counts solutions to `sudoku` under `constraint`, stopping as soon as
+// `limit` are found. The constraint-aware sibling of `count_solutions_upto`,
+// used by `core::generator` to confirm a variant puzzle is still uniquely
+// solvable while digging clues out of it.
+pub fn count_solutions_with_constraints_upto(
+    sudoku: &Sudoku,
+    limit: usize,
+    constraint: &dyn Constraint,
+) -> (usize, SolverStats) {
+    let start_time = Instant::now();
+    let side = sudoku.side();
+    let mut stats = SolverStats::new(side);
+
+    let mut board: Vec<Vec<u8>> = vec![vec![0u8; side]; side];
+    for (r, row) in board.iter_mut().enumerate() {
+        for (c, cell) in row.iter_mut().enumerate() {
+            if let Some(value) = sudoku.get_solved_value(r, c) {
+                *cell = value;
+            }
+        }
+    }
+
+    let peers: Vec<Vec<(usize, usize)>> = (0..side)
+        .flat_map(|r| (0..side).map(move |c| (r, c)))
+        .map(|(r, c)| constraint.affected_masks(side, r, c))
+        .collect();
+
+    let mut forbidden_counts: Vec<Vec<u16>> = vec![vec![0u16; side]; side * side];
+    let mut forbidden_mask: Vec<u32> = vec![0u32; side * side];
+    for r in 0..side {
+        for c in 0..side {
+            if board[r][c] != 0 {
+                on_place_constrained(&peers, side, r, c, board[r][c], &mut forbidden_counts, &mut forbidden_mask);
+            }
+        }
+    }
+
+    let cell_order: Vec<(usize, usize)> = (0..side).flat_map(|r| (0..side).map(move |c| (r, c))).collect();
+
+    let mut solutions: Vec<Vec<Vec<u8>>> = Vec::new();
+    solve_recursive_constrained(
+        &mut board,
+        side,
+        &peers,
+        &mut forbidden_counts,
+        &mut forbidden_mask,
+        &cell_order,
+        0,
+        0,
+        &mut stats,
+        &mut solutions,
+        Some(limit),
+    );
+
+    stats.solutions_found = solutions.len();
+    stats.search_duration = start_time.elapsed();
+    (solutions.len(), stats)
 }
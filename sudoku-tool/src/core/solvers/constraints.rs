@@ -0,0 +1,394 @@
+//! Pluggable placement rules for `core::solvers::bf_solver`'s constraint-
+//! aware entry point. `find_one_solution_strategy`'s `is_safe` hard-codes
+//! row/column/box uniqueness via per-unit bitmasks, so it can only ever
+//! solve classic Sudoku; this module extracts that same rule behind a
+//! `Constraint` trait so popular variants (X-sudoku, windoku, anti-knight)
+//! can be composed and solved by `find_one_solution_with_constraints`
+//! without forking the search.
+//!
+//! Every rule here is a "peer" constraint: a value may not repeat among a
+//! cell's peers. That covers every variant family this module ships with
+//! today. A constraint that needs richer bookkeeping (a killer-cage sum,
+//! say) can still implement `check`, but `find_one_solution_with_constraints`
+//! only consults the cached peer sets, so such a constraint would need its
+//! peers to be the cells its placements actually conflict with.
+
+use std::collections::HashSet;
+
+use crate::core::sudoku::Sudoku;
+
+/// A single placement rule for a variant Sudoku board.
+pub trait Constraint {
+    /// Whether `val` may legally be placed at (`row`, `col`) given
+    /// everything already on `board`. This is the rule's semantics; the
+    /// search itself consults the cached peer sets from `affected_masks`
+    /// instead of calling this for every candidate, and a puzzle's starting
+    /// clues are not checked against it.
+    fn check(&self, board: &Sudoku, row: usize, col: usize, val: u8) -> bool;
+
+    /// The cells a placement at (`row`, `col`) must not share a value
+    /// with, i.e. this rule's "peers" of that cell. `side` is the board's
+    /// side length. Cached once per cell when a search starts.
+    fn affected_masks(&self, side: usize, row: usize, col: usize) -> Vec<(usize, usize)>;
+}
+
+/// Row/column/box uniqueness: the rules of classic Sudoku, reimplemented
+/// against the peer-set API so they compose with the variant constraints
+/// below via `CompositeConstraint`.
+pub struct DefaultConstraint {
+    box_size: usize,
+}
+
+impl DefaultConstraint {
+    pub fn new(box_size: usize) -> Self {
+        Self { box_size }
+    }
+}
+
+impl Constraint for DefaultConstraint {
+    fn check(&self, board: &Sudoku, row: usize, col: usize, val: u8) -> bool {
+        self.affected_masks(board.side(), row, col)
+            .into_iter()
+            .all(|(r, c)| board.get_solved_value(r, c) != Some(val))
+    }
+
+    fn affected_masks(&self, side: usize, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let mut peers = HashSet::new();
+        for c in 0..side {
+            if c != col {
+                peers.insert((row, c));
+            }
+        }
+        for r in 0..side {
+            if r != row {
+                peers.insert((r, col));
+            }
+        }
+        let box_row = (row / self.box_size) * self.box_size;
+        let box_col = (col / self.box_size) * self.box_size;
+        for r in box_row..box_row + self.box_size {
+            for c in box_col..box_col + self.box_size {
+                if (r, c) != (row, col) {
+                    peers.insert((r, c));
+                }
+            }
+        }
+        peers.into_iter().collect()
+    }
+}
+
+/// X-sudoku: both main diagonals must also contain each value exactly once.
+pub struct DiagonalConstraint;
+
+impl DiagonalConstraint {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn on_main(row: usize, col: usize) -> bool {
+        row == col
+    }
+
+    fn on_anti(side: usize, row: usize, col: usize) -> bool {
+        row + col == side - 1
+    }
+}
+
+impl Default for DiagonalConstraint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Constraint for DiagonalConstraint {
+    fn check(&self, board: &Sudoku, row: usize, col: usize, val: u8) -> bool {
+        self.affected_masks(board.side(), row, col)
+            .into_iter()
+            .all(|(r, c)| board.get_solved_value(r, c) != Some(val))
+    }
+
+    fn affected_masks(&self, side: usize, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let mut peers = Vec::new();
+        if Self::on_main(row, col) {
+            peers.extend((0..side).map(|i| (i, i)).filter(|&cell| cell != (row, col)));
+        }
+        if Self::on_anti(side, row, col) {
+            peers.extend(
+                (0..side)
+                    .map(|i| (i, side - 1 - i))
+                    .filter(|&cell| cell != (row, col)),
+            );
+        }
+        peers
+    }
+}
+
+/// Windoku/hyper-sudoku: four extra non-overlapping `box_size`x`box_size`
+/// regions, each inset one row/column from the grid's edges, must also
+/// contain each value exactly once.
+pub struct HyperBoxConstraint {
+    box_size: usize,
+}
+
+impl HyperBoxConstraint {
+    pub fn new(box_size: usize) -> Self {
+        Self { box_size }
+    }
+
+    // The top-left corner of each of the four hyper-boxes, inset one band
+    // from the grid edges (the classic windoku layout for box_size=3).
+    fn hyper_box_origins(&self) -> [(usize, usize); 4] {
+        let b = self.box_size;
+        let far = (b * b).saturating_sub(b + 1);
+        [(1, 1), (1, far), (far, 1), (far, far)]
+    }
+
+    fn hyper_box_containing(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        self.hyper_box_origins()
+            .into_iter()
+            .find(|&(origin_row, origin_col)| {
+                row >= origin_row
+                    && row < origin_row + self.box_size
+                    && col >= origin_col
+                    && col < origin_col + self.box_size
+            })
+    }
+}
+
+impl Constraint for HyperBoxConstraint {
+    fn check(&self, board: &Sudoku, row: usize, col: usize, val: u8) -> bool {
+        self.affected_masks(board.side(), row, col)
+            .into_iter()
+            .all(|(r, c)| board.get_solved_value(r, c) != Some(val))
+    }
+
+    fn affected_masks(&self, _side: usize, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let Some((origin_row, origin_col)) = self.hyper_box_containing(row, col) else {
+            return Vec::new();
+        };
+        let mut peers = Vec::new();
+        for r in origin_row..origin_row + self.box_size {
+            for c in origin_col..origin_col + self.box_size {
+                if (r, c) != (row, col) {
+                    peers.push((r, c));
+                }
+            }
+        }
+        peers
+    }
+}
+
+/// Anti-knight: no two cells a chess knight's move apart may share a value.
+pub struct AntiKnightConstraint;
+
+impl AntiKnightConstraint {
+    pub fn new() -> Self {
+        Self
+    }
+
+    const KNIGHT_OFFSETS: [(i64, i64); 8] = [
+        (-2, -1),
+        (-2, 1),
+        (-1, -2),
+        (-1, 2),
+        (1, -2),
+        (1, 2),
+        (2, -1),
+        (2, 1),
+    ];
+}
+
+impl Default for AntiKnightConstraint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Constraint for AntiKnightConstraint {
+    fn check(&self, board: &Sudoku, row: usize, col: usize, val: u8) -> bool {
+        self.affected_masks(board.side(), row, col)
+            .into_iter()
+            .all(|(r, c)| board.get_solved_value(r, c) != Some(val))
+    }
+
+    fn affected_masks(&self, side: usize, row: usize, col: usize) -> Vec<(usize, usize)> {
+        Self::KNIGHT_OFFSETS
+            .iter()
+            .filter_map(|(dr, dc)| {
+                let nr = row as i64 + dr;
+                let nc = col as i64 + dc;
+                if nr >= 0 && nc >= 0 && (nr as usize) < side && (nc as usize) < side {
+                    Some((nr as usize, nc as usize))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Anti-king: no two (orthogonally or diagonally) adjacent cells may share
+/// a value.
+pub struct AntiKingConstraint;
+
+impl AntiKingConstraint {
+    pub fn new() -> Self {
+        Self
+    }
+
+    const KING_OFFSETS: [(i64, i64); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+}
+
+impl Default for AntiKingConstraint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Constraint for AntiKingConstraint {
+    fn check(&self, board: &Sudoku, row: usize, col: usize, val: u8) -> bool {
+        self.affected_masks(board.side(), row, col)
+            .into_iter()
+            .all(|(r, c)| board.get_solved_value(r, c) != Some(val))
+    }
+
+    fn affected_masks(&self, side: usize, row: usize, col: usize) -> Vec<(usize, usize)> {
+        Self::KING_OFFSETS
+            .iter()
+            .filter_map(|(dr, dc)| {
+                let nr = row as i64 + dr;
+                let nc = col as i64 + dc;
+                if nr >= 0 && nc >= 0 && (nr as usize) < side && (nc as usize) < side {
+                    Some((nr as usize, nc as usize))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// ANDs several constraints together: a placement is legal only if every
+/// one of them allows it, and its combined peer set is the union of
+/// theirs.
+pub struct CompositeConstraint(pub Vec<Box<dyn Constraint>>);
+
+impl CompositeConstraint {
+    pub fn new(constraints: Vec<Box<dyn Constraint>>) -> Self {
+        Self(constraints)
+    }
+}
+
+impl Constraint for CompositeConstraint {
+    fn check(&self, board: &Sudoku, row: usize, col: usize, val: u8) -> bool {
+        self.0.iter().all(|c| c.check(board, row, col, val))
+    }
+
+    fn affected_masks(&self, side: usize, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let mut peers: HashSet<(usize, usize)> = HashSet::new();
+        for constraint in &self.0 {
+            peers.extend(constraint.affected_masks(side, row, col));
+        }
+        peers.into_iter().collect()
+    }
+}
+
+/// The classic Sudoku rule set (row/column/box) for a board of the given
+/// box size, wrapped so it can be passed wherever a `&dyn Constraint` is
+/// expected.
+pub fn classic_constraints(box_size: usize) -> CompositeConstraint {
+    CompositeConstraint::new(vec![Box::new(DefaultConstraint::new(box_size))])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved_row(value_at_0_0: u8) -> Sudoku {
+        let mut board = Sudoku::new();
+        board.set_cell(0, 0, value_at_0_0).unwrap();
+        board
+    }
+
+    #[test]
+    fn test_default_constraint_blocks_row_duplicate() {
+        let board = solved_row(5);
+        let constraint = DefaultConstraint::new(3);
+        assert!(!constraint.check(&board, 0, 3, 5));
+        assert!(constraint.check(&board, 0, 3, 6));
+        assert!(constraint.check(&board, 1, 3, 5));
+    }
+
+    #[test]
+    fn test_diagonal_constraint_main_and_anti() {
+        let mut board = Sudoku::new();
+        board.set_cell(0, 0, 7).unwrap();
+        let constraint = DiagonalConstraint::new();
+        assert!(!constraint.check(&board, 4, 4, 7));
+        assert!(constraint.check(&board, 0, 1, 7));
+
+        board.set_cell(0, 8, 2).unwrap();
+        assert!(!constraint.check(&board, 8, 0, 2));
+    }
+
+    #[test]
+    fn test_hyper_box_constraint() {
+        let mut board = Sudoku::new();
+        board.set_cell(1, 1, 4).unwrap();
+        let constraint = HyperBoxConstraint::new(3);
+        assert!(!constraint.check(&board, 2, 2, 4)); // same hyper-box
+        assert!(constraint.check(&board, 0, 0, 4)); // not in any hyper-box
+    }
+
+    #[test]
+    fn test_anti_knight_constraint() {
+        let mut board = Sudoku::new();
+        board.set_cell(0, 0, 4).unwrap();
+        let constraint = AntiKnightConstraint::new();
+        assert!(!constraint.check(&board, 1, 2, 4));
+        assert!(!constraint.check(&board, 2, 1, 4));
+        assert!(constraint.check(&board, 1, 1, 4));
+    }
+
+    #[test]
+    fn test_anti_king_constraint() {
+        let mut board = Sudoku::new();
+        board.set_cell(4, 4, 6).unwrap();
+        let constraint = AntiKingConstraint::new();
+        assert!(!constraint.check(&board, 3, 3, 6));
+        assert!(!constraint.check(&board, 5, 5, 6));
+        assert!(constraint.check(&board, 2, 4, 6));
+    }
+
+    #[test]
+    fn test_composite_constraint_ands_and_unions_peers() {
+        // (0, 3) puts the clue in a box (rows 0-2, cols 3-5) that doesn't
+        // overlap (1, 1), so that cell's only conflict is via anti-knight.
+        let mut board = Sudoku::new();
+        board.set_cell(0, 3, 4).unwrap();
+        let composite = CompositeConstraint::new(vec![
+            Box::new(DefaultConstraint::new(3)),
+            Box::new(AntiKnightConstraint::new()),
+        ]);
+
+        // Blocked by the row rule (same row as (0, 3)).
+        assert!(!composite.check(&board, 0, 5, 4));
+        // Blocked by anti-knight only (a knight's move from (0, 3)).
+        assert!(!composite.check(&board, 1, 1, 4));
+        // Allowed by both.
+        assert!(composite.check(&board, 5, 5, 4));
+
+        let peers = composite.affected_masks(9, 0, 3);
+        assert!(peers.contains(&(0, 5))); // from DefaultConstraint's row
+        assert!(peers.contains(&(1, 1))); // from AntiKnightConstraint
+    }
+}
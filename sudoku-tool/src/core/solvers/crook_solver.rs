@@ -1,5 +1,5 @@
-use crate::core::sudoku::Sudoku;
-use std::collections::{BTreeSet, HashMap, HashSet};
+use crate::core::sudoku::{CandidateMask, Sudoku};
+use std::collections::BTreeSet;
 use std::time::{Duration, Instant};
 
 /*
@@ -55,25 +55,25 @@ pub enum RangeType {
 }
 
 impl PreemptiveSet {
-    fn new(numbers: BTreeSet<u8>, cells: Vec<(usize, usize)>) -> Self {
+    fn new(numbers: BTreeSet<u8>, cells: Vec<(usize, usize)>, box_size: usize) -> Self {
         let mut ranges = Vec::new();
-        
+
         // Determine which ranges this set applies to
         if let Some(row) = Self::get_common_row(&cells) {
             ranges.push(RangeType::Row(row));
         }
-        
+
         if let Some(col) = Self::get_common_column(&cells) {
             ranges.push(RangeType::Column(col));
         }
-        
-        if let Some(box_idx) = Self::get_common_box(&cells) {
+
+        if let Some(box_idx) = Self::get_common_box(&cells, box_size) {
             ranges.push(RangeType::Box(box_idx));
         }
-        
+
         PreemptiveSet { numbers, cells, ranges }
     }
-    
+
     fn get_common_row(cells: &[(usize, usize)]) -> Option<usize> {
         let first_row = cells[0].0;
         if cells.iter().all(|&(r, _)| r == first_row) {
@@ -82,7 +82,7 @@ impl PreemptiveSet {
             None
         }
     }
-    
+
     fn get_common_column(cells: &[(usize, usize)]) -> Option<usize> {
         let first_col = cells[0].1;
         if cells.iter().all(|&(_, c)| c == first_col) {
@@ -91,19 +91,255 @@ impl PreemptiveSet {
             None
         }
     }
-    
-    fn get_common_box(cells: &[(usize, usize)]) -> Option<usize> {
-        let first_box = (cells[0].0 / 3) * 3 + (cells[0].1 / 3);
-        if cells.iter().all(|&(r, c)| (r / 3) * 3 + (c / 3) == first_box) {
+
+    fn get_common_box(cells: &[(usize, usize)], box_size: usize) -> Option<usize> {
+        let box_of = |r: usize, c: usize| (r / box_size) * box_size + c / box_size;
+        let first_box = box_of(cells[0].0, cells[0].1);
+        if cells.iter().all(|&(r, c)| box_of(r, c) == first_box) {
             Some(first_box)
         } else {
             None
         }
     }
-    
+
     // Check if this preemptive set applies to a specific range
     fn applies_to_range(&self, range_type: &RangeType) -> bool {
         self.ranges.contains(range_type)
     }
 }
 
+// The cell groups ("units") that must each contain every digit exactly
+// once: every row, every column, and every box, tagged with the
+// `RangeType` that a `PreemptiveSet` found there would report.
+fn ranges(side: usize, box_size: usize) -> Vec<(RangeType, Vec<(usize, usize)>)> {
+    let mut units = Vec::new();
+
+    for row in 0..side {
+        units.push((RangeType::Row(row), (0..side).map(|c| (row, c)).collect()));
+    }
+    for col in 0..side {
+        units.push((
+            RangeType::Column(col),
+            (0..side).map(|r| (r, col)).collect(),
+        ));
+    }
+    for box_row in 0..box_size {
+        for box_col in 0..box_size {
+            let cells = (0..box_size)
+                .flat_map(|dr| (0..box_size).map(move |dc| (dr, dc)))
+                .map(|(dr, dc)| (box_row * box_size + dr, box_col * box_size + dc))
+                .collect();
+            units.push((RangeType::Box(box_row * box_size + box_col), cells));
+        }
+    }
+
+    units
+}
+
+// Every k-element subset of `items`, in index order. Naked sets are only
+// ever small (pairs, triples, quads), so a plain recursive generator is
+// plenty fast for the range sizes (9, 16, 25, ...) this solver sees.
+fn combinations<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+    if items.len() < k {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+    for i in 0..=items.len() - k {
+        let rest = combinations(&items[i + 1..], k - 1);
+        for mut tail in rest {
+            let mut combo = vec![items[i].clone()];
+            combo.append(&mut tail);
+            result.push(combo);
+        }
+    }
+    result
+}
+
+// Drives the rule-based solve described at the top of this file: repeated
+// passes of naked-set crossout and forced-singleton placement, falling
+// back to MRV-guided backtracking only once the logical rules stall.
+pub struct CrookSolver {
+    pub puzzle: Sudoku,
+    stats: PencilPaperStats,
+}
+
+impl CrookSolver {
+    pub fn new(puzzle: Sudoku) -> Self {
+        Self {
+            puzzle,
+            stats: PencilPaperStats::default(),
+        }
+    }
+
+    pub fn solve(&mut self) -> PencilPaperStats {
+        let start = Instant::now();
+
+        self.puzzle.markup_empty_cells();
+        self.run_logical_passes();
+
+        if !self.puzzle.is_solved() {
+            if let Some(solved) = Self::backtrack(self.puzzle.clone(), &mut self.stats) {
+                self.puzzle = solved;
+            }
+        }
+
+        self.stats.solutions_found = if self.puzzle.is_solved() { 1 } else { 0 };
+        self.stats.search_duration = start.elapsed();
+        self.stats.clone()
+    }
+
+    // Alternates naked-set crossout and forced-singleton placement until a
+    // full pass makes no further change.
+    fn run_logical_passes(&mut self) {
+        loop {
+            self.stats.iterations += 1;
+
+            let crossed_out = self.apply_preemptive_sets();
+            let placed = self.place_forced_singles();
+
+            if !crossed_out && !placed {
+                break;
+            }
+        }
+    }
+
+    // Places every cell that's been reduced to a single candidate,
+    // propagating the placement to its peers.
+    fn place_forced_singles(&mut self) -> bool {
+        let side = self.puzzle.side();
+        let mut changed = false;
+
+        for row in 0..side {
+            for col in 0..side {
+                if self.puzzle.get_solved_value(row, col).is_some() {
+                    continue;
+                }
+
+                let mask = self.puzzle.candidate_mask(row, col);
+                if mask.count_ones() == 1 {
+                    let value = mask.trailing_zeros() as u8 + 1;
+                    self.puzzle.set_cell(row, col, value).unwrap();
+                    self.puzzle.remove_value_from_peers(row, col);
+                    self.stats.forced_numbers_placed += 1;
+                    changed = true;
+                }
+            }
+        }
+
+        changed
+    }
+
+    // Scans every row/column/box for naked sets of size 2-4 and crosses
+    // their digits out of every other cell in each range they apply to.
+    fn apply_preemptive_sets(&mut self) -> bool {
+        let side = self.puzzle.side();
+        let box_size = self.puzzle.box_size;
+        let mut changed = false;
+
+        for (range_type, cells) in ranges(side, box_size) {
+            let unsolved: Vec<(usize, usize)> = cells
+                .iter()
+                .copied()
+                .filter(|&(r, c)| self.puzzle.get_solved_value(r, c).is_none())
+                .collect();
+
+            let max_set_size = unsolved.len().saturating_sub(1).min(4);
+            for set_size in 2..=max_set_size {
+                for combo in combinations(&unsolved, set_size) {
+                    let union_mask = combo
+                        .iter()
+                        .fold(CandidateMask::default(), |acc, &(r, c)| {
+                            acc | self.puzzle.candidate_mask(r, c)
+                        });
+
+                    if union_mask.count_ones() as usize != set_size {
+                        continue;
+                    }
+
+                    let numbers: BTreeSet<u8> = (0..side as u8)
+                        .filter(|&d| union_mask & (1 << d) != 0)
+                        .map(|d| d + 1)
+                        .collect();
+
+                    let set = PreemptiveSet::new(numbers.clone(), combo.clone(), box_size);
+                    if !set.applies_to_range(&range_type) {
+                        continue;
+                    }
+
+                    let mut crossed_out_any = false;
+                    for &(r, c) in &cells {
+                        if combo.contains(&(r, c)) || self.puzzle.get_solved_value(r, c).is_some() {
+                            continue;
+                        }
+                        for &digit in &numbers {
+                            if self.puzzle.remove_possibility(r, c, digit) {
+                                crossed_out_any = true;
+                            }
+                        }
+                    }
+
+                    if crossed_out_any {
+                        self.stats.preemptive_sets_found += 1;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        changed
+    }
+
+    // MRV-guided backtracking fallback: picks the unsolved cell with the
+    // fewest remaining candidates and tries each in turn, recursing on a
+    // cloned board. Returns the first complete solution found, if any.
+    fn backtrack(puzzle: Sudoku, stats: &mut PencilPaperStats) -> Option<Sudoku> {
+        let side = puzzle.side();
+
+        let mut best: Option<(usize, usize, CandidateMask, u32)> = None;
+        for row in 0..side {
+            for col in 0..side {
+                if puzzle.get_solved_value(row, col).is_some() {
+                    continue;
+                }
+                let mask = puzzle.candidate_mask(row, col);
+                let count = mask.count_ones();
+                if count == 0 {
+                    return None; // Dead end: no candidates left for this cell
+                }
+                let is_better = match best {
+                    Some((_, _, _, best_count)) => count < best_count,
+                    None => true,
+                };
+                if is_better {
+                    best = Some((row, col, mask, count));
+                }
+            }
+        }
+
+        let Some((row, col, mask, _)) = best else {
+            return Some(puzzle); // No unsolved cells left
+        };
+
+        for digit in 1..=side as u8 {
+            if mask & (1 << (digit - 1)) == 0 {
+                continue;
+            }
+
+            stats.iterations += 1;
+            let mut next = puzzle.clone();
+            next.set_cell(row, col, digit).unwrap();
+            next.remove_value_from_peers(row, col);
+
+            if let Some(solved) = Self::backtrack(next, stats) {
+                return Some(solved);
+            }
+        }
+
+        None
+    }
+}
+
@@ -0,0 +1,551 @@
+//! Human-style logical solver. Unlike `bf_solver`, this never guesses: it
+//! repeatedly applies deduction techniques (cheapest first) to a per-cell
+//! candidate bitset until either the board is solved or nothing more
+//! fires, recording each deduction as a typed, renderable `SolveStep` so
+//! callers get an explainable walkthrough rather than just a filled grid.
+//! A puzzle that can't be finished by logic alone falls back to
+//! `bf_solver::find_one_solution`, with the cells it had to fill in
+//! reported as `SolveStep::Guess`.
+
+use crate::core::solvers::bf_solver::find_one_solution;
+use crate::core::sudoku::Sudoku;
+
+/// Which row, column, or box a step's deduction happened within, for
+/// rendering things like "hidden single in box 2".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Group {
+    Row(usize),
+    Column(usize),
+    Box(usize),
+}
+
+impl Group {
+    fn describe(&self) -> String {
+        match *self {
+            Group::Row(r) => format!("row {}", r + 1),
+            Group::Column(c) => format!("column {}", column_label(c)),
+            Group::Box(b) => format!("box {}", b + 1),
+        }
+    }
+}
+
+/// A single deduction (or, once logic stalls, a guess) made while solving
+/// a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveStep {
+    /// A cell with exactly one remaining candidate.
+    NakedSingle { cell: (usize, usize), value: u8 },
+    /// A digit that only one cell in `group` can still hold.
+    HiddenSingle {
+        group: Group,
+        cell: (usize, usize),
+        value: u8,
+    },
+    /// Pointing/claiming: `value` is confined to the cells it shares
+    /// between `group` and another unit, so it can be eliminated from
+    /// `eliminated_from` (the rest of whichever unit isn't `group`).
+    LockedCandidates {
+        group: Group,
+        value: u8,
+        eliminated_from: Vec<(usize, usize)>,
+    },
+    /// Logic stalled before the board was complete; this cell's value
+    /// came from the backtracking fallback solver rather than a
+    /// deduction.
+    Guess { cell: (usize, usize), value: u8 },
+}
+
+impl SolveStep {
+    /// Render the step in chess-style coordinates (column letters, row
+    /// digits), e.g. "C5 = 7 (hidden single in box 2)".
+    pub fn render(&self) -> String {
+        match self {
+            SolveStep::NakedSingle { cell, value } => {
+                format!("{} = {} (naked single)", cell_label(*cell), value)
+            }
+            SolveStep::HiddenSingle { group, cell, value } => format!(
+                "{} = {} (hidden single in {})",
+                cell_label(*cell),
+                value,
+                group.describe()
+            ),
+            SolveStep::LockedCandidates {
+                group,
+                value,
+                eliminated_from,
+            } => format!(
+                "{} locked to {}: removed from {}",
+                value,
+                group.describe(),
+                eliminated_from
+                    .iter()
+                    .map(|&cell| cell_label(cell))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            SolveStep::Guess { cell, value } => {
+                format!("{} = {} (guess via backtracking)", cell_label(*cell), value)
+            }
+        }
+    }
+}
+
+// Column letters A-Z; boards wider than 26 columns fall back to a
+// bracketed 1-based index since the alphabet runs out.
+fn column_label(col: usize) -> String {
+    if col < 26 {
+        ((b'A' + col as u8) as char).to_string()
+    } else {
+        format!("[{}]", col + 1)
+    }
+}
+
+fn cell_label(cell: (usize, usize)) -> String {
+    format!("{}{}", column_label(cell.1), cell.0 + 1)
+}
+
+/// The result of attempting to solve a puzzle by pure deduction.
+pub enum LogicSolveOutcome {
+    /// The board was fully solved using only logical deduction.
+    SolvedByLogic { solution: Sudoku, steps: Vec<SolveStep> },
+    /// Deduction stalled before the board was complete; `solution` comes
+    /// from falling back to `find_one_solution`.
+    FellBackToSearch {
+        steps: Vec<SolveStep>,
+        solution: Option<Sudoku>,
+    },
+}
+
+/// Attempt to solve `sudoku` by deduction alone, reporting the sequence of
+/// techniques used. Falls back to brute-force search if logic alone can't
+/// finish the board.
+pub fn solve_logic(sudoku: &Sudoku) -> LogicSolveOutcome {
+    let side = sudoku.side();
+    let box_size = sudoku.box_size;
+
+    let mut board = vec![vec![0u8; side]; side];
+    let mut candidates = vec![vec![0u32; side]; side];
+    initialize(sudoku, &mut board, &mut candidates, side, box_size);
+
+    let mut steps = Vec::new();
+
+    loop {
+        if naked_singles_pass(&mut board, &mut candidates, side, box_size, &mut steps) {
+            continue;
+        }
+        if hidden_singles_pass(&mut board, &mut candidates, side, box_size, &mut steps) {
+            continue;
+        }
+        if locked_candidates_pass(&board, &mut candidates, side, box_size, &mut steps) {
+            continue;
+        }
+        break;
+    }
+
+    let solved = board.iter().all(|row| row.iter().all(|&v| v != 0));
+
+    if solved {
+        let mut solution = Sudoku::new_with_box_size(box_size);
+        for i in 0..side {
+            for j in 0..side {
+                solution.set_cell(i, j, board[i][j]).unwrap();
+            }
+        }
+        LogicSolveOutcome::SolvedByLogic { solution, steps }
+    } else {
+        // Hand the partially-reduced board to the backtracking engine,
+        // then report every cell it still had to fill in as a `Guess`
+        // step (logic alone couldn't tell how it got there).
+        let preset: Vec<Vec<Option<u8>>> = board
+            .iter()
+            .map(|row| row.iter().map(|&v| if v == 0 { None } else { Some(v) }).collect())
+            .collect();
+        let partial = Sudoku::from_preset(preset);
+
+        let (solution, _stats) = find_one_solution(&partial);
+        if let Some(ref solved_puzzle) = solution {
+            for (i, row) in board.iter().enumerate() {
+                for (j, &v) in row.iter().enumerate() {
+                    if v == 0 {
+                        let value = solved_puzzle.get_solved_value(i, j).unwrap();
+                        steps.push(SolveStep::Guess { cell: (i, j), value });
+                    }
+                }
+            }
+        }
+        LogicSolveOutcome::FellBackToSearch { steps, solution }
+    }
+}
+
+fn full_mask(side: usize) -> u32 {
+    if side >= 31 {
+        u32::MAX
+    } else {
+        (1 << (side + 1)) - 2
+    }
+}
+
+fn initialize(
+    sudoku: &Sudoku,
+    board: &mut [Vec<u8>],
+    candidates: &mut [Vec<u32>],
+    side: usize,
+    box_size: usize,
+) {
+    let mut rows = vec![0u32; side];
+    let mut cols = vec![0u32; side];
+    let mut subgrids = vec![0u32; side];
+
+    for i in 0..side {
+        for j in 0..side {
+            if let Some(value) = sudoku.get_solved_value(i, j) {
+                board[i][j] = value;
+                let bit = 1 << value;
+                rows[i] |= bit;
+                cols[j] |= bit;
+                subgrids[(i / box_size) * box_size + j / box_size] |= bit;
+            }
+        }
+    }
+
+    let full = full_mask(side);
+    for i in 0..side {
+        for j in 0..side {
+            if board[i][j] == 0 {
+                let used = rows[i] | cols[j] | subgrids[(i / box_size) * box_size + j / box_size];
+                candidates[i][j] = full & !used;
+            }
+        }
+    }
+}
+
+// Places `val` at (i, j) and removes it from the candidates of every peer
+// (same row, column, and box).
+fn place_value(
+    board: &mut [Vec<u8>],
+    candidates: &mut [Vec<u32>],
+    side: usize,
+    box_size: usize,
+    i: usize,
+    j: usize,
+    val: u8,
+) {
+    board[i][j] = val;
+    candidates[i][j] = 0;
+    let bit = 1 << val;
+
+    for c in 0..side {
+        if board[i][c] == 0 {
+            candidates[i][c] &= !bit;
+        }
+    }
+    for r in 0..side {
+        if board[r][j] == 0 {
+            candidates[r][j] &= !bit;
+        }
+    }
+
+    let box_row = (i / box_size) * box_size;
+    let box_col = (j / box_size) * box_size;
+    for r in box_row..box_row + box_size {
+        for c in box_col..box_col + box_size {
+            if board[r][c] == 0 {
+                candidates[r][c] &= !bit;
+            }
+        }
+    }
+}
+
+// A cell with exactly one remaining candidate must hold that value.
+fn naked_singles_pass(
+    board: &mut [Vec<u8>],
+    candidates: &mut [Vec<u32>],
+    side: usize,
+    box_size: usize,
+    steps: &mut Vec<SolveStep>,
+) -> bool {
+    let mut changed = false;
+    for i in 0..side {
+        for j in 0..side {
+            if board[i][j] == 0 && candidates[i][j].count_ones() == 1 {
+                let val = candidates[i][j].trailing_zeros() as u8;
+                place_value(board, candidates, side, box_size, i, j, val);
+                steps.push(SolveStep::NakedSingle {
+                    cell: (i, j),
+                    value: val,
+                });
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+// A digit that appears as a candidate in only one cell of a unit must go
+// there, even if that cell has other candidates too.
+fn hidden_singles_pass(
+    board: &mut [Vec<u8>],
+    candidates: &mut [Vec<u32>],
+    side: usize,
+    box_size: usize,
+    steps: &mut Vec<SolveStep>,
+) -> bool {
+    let mut changed = false;
+    for (group, unit) in units(side, box_size) {
+        for val in 1..=side as u8 {
+            let bit = 1 << val;
+            let mut hit = None;
+            let mut count = 0;
+            for &(i, j) in &unit {
+                if board[i][j] == 0 && candidates[i][j] & bit != 0 {
+                    count += 1;
+                    hit = Some((i, j));
+                }
+            }
+            if count == 1 {
+                let (i, j) = hit.unwrap();
+                place_value(board, candidates, side, box_size, i, j, val);
+                steps.push(SolveStep::HiddenSingle {
+                    group,
+                    cell: (i, j),
+                    value: val,
+                });
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+// Pointing: a digit confined to one box-row/box-col within a box can be
+// eliminated from the rest of that row/col outside the box.
+// Claiming: a digit confined to one box within a row/col can be
+// eliminated from the rest of that box outside the row/col.
+fn locked_candidates_pass(
+    board: &[Vec<u8>],
+    candidates: &mut [Vec<u32>],
+    side: usize,
+    box_size: usize,
+    steps: &mut Vec<SolveStep>,
+) -> bool {
+    let mut changed = false;
+
+    // Pointing: scan each box.
+    for bi in 0..box_size {
+        for bj in 0..box_size {
+            let box_idx = bi * box_size + bj;
+            let box_cells: Vec<(usize, usize)> = (0..box_size)
+                .flat_map(|x| (0..box_size).map(move |y| (bi * box_size + x, bj * box_size + y)))
+                .filter(|&(i, j)| board[i][j] == 0)
+                .collect();
+
+            for val in 1..=side as u8 {
+                let bit = 1 << val;
+                let holders: Vec<(usize, usize)> = box_cells
+                    .iter()
+                    .copied()
+                    .filter(|&(i, j)| candidates[i][j] & bit != 0)
+                    .collect();
+                if holders.is_empty() {
+                    continue;
+                }
+
+                if holders.iter().all(|&(i, _)| i == holders[0].0) {
+                    let row = holders[0].0;
+                    let eliminated = eliminate_in_row(candidates, board, side, box_size, row, bj, bit);
+                    if !eliminated.is_empty() {
+                        steps.push(SolveStep::LockedCandidates {
+                            group: Group::Box(box_idx),
+                            value: val,
+                            eliminated_from: eliminated,
+                        });
+                        changed = true;
+                    }
+                } else if holders.iter().all(|&(_, j)| j == holders[0].1) {
+                    let col = holders[0].1;
+                    let eliminated = eliminate_in_column(candidates, board, side, box_size, col, bi, bit);
+                    if !eliminated.is_empty() {
+                        steps.push(SolveStep::LockedCandidates {
+                            group: Group::Box(box_idx),
+                            value: val,
+                            eliminated_from: eliminated,
+                        });
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    // Claiming: scan each row and column.
+    for row in 0..side {
+        for val in 1..=side as u8 {
+            let bit = 1 << val;
+            let holders: Vec<usize> = (0..side)
+                .filter(|&c| board[row][c] == 0 && candidates[row][c] & bit != 0)
+                .collect();
+            if holders.is_empty() {
+                continue;
+            }
+            let box_col = holders[0] / box_size;
+            if holders.iter().all(|&c| c / box_size == box_col) {
+                let eliminated = eliminate_in_box_outside_row(candidates, board, box_size, row, box_col, bit);
+                if !eliminated.is_empty() {
+                    steps.push(SolveStep::LockedCandidates {
+                        group: Group::Row(row),
+                        value: val,
+                        eliminated_from: eliminated,
+                    });
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    for col in 0..side {
+        for val in 1..=side as u8 {
+            let bit = 1 << val;
+            let holders: Vec<usize> = (0..side)
+                .filter(|&r| board[r][col] == 0 && candidates[r][col] & bit != 0)
+                .collect();
+            if holders.is_empty() {
+                continue;
+            }
+            let box_row = holders[0] / box_size;
+            if holders.iter().all(|&r| r / box_size == box_row) {
+                let eliminated = eliminate_in_box_outside_column(candidates, board, box_size, col, box_row, bit);
+                if !eliminated.is_empty() {
+                    steps.push(SolveStep::LockedCandidates {
+                        group: Group::Column(col),
+                        value: val,
+                        eliminated_from: eliminated,
+                    });
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    changed
+}
+
+fn eliminate_in_row(
+    candidates: &mut [Vec<u32>],
+    board: &[Vec<u8>],
+    side: usize,
+    box_size: usize,
+    row: usize,
+    box_col: usize,
+    bit: u32,
+) -> Vec<(usize, usize)> {
+    let mut eliminated = Vec::new();
+    for c in 0..side {
+        if c / box_size == box_col || board[row][c] != 0 {
+            continue;
+        }
+        let before = candidates[row][c];
+        candidates[row][c] &= !bit;
+        if candidates[row][c] != before {
+            eliminated.push((row, c));
+        }
+    }
+    eliminated
+}
+
+fn eliminate_in_column(
+    candidates: &mut [Vec<u32>],
+    board: &[Vec<u8>],
+    side: usize,
+    box_size: usize,
+    col: usize,
+    box_row: usize,
+    bit: u32,
+) -> Vec<(usize, usize)> {
+    let mut eliminated = Vec::new();
+    for r in 0..side {
+        if r / box_size == box_row || board[r][col] != 0 {
+            continue;
+        }
+        let before = candidates[r][col];
+        candidates[r][col] &= !bit;
+        if candidates[r][col] != before {
+            eliminated.push((r, col));
+        }
+    }
+    eliminated
+}
+
+fn eliminate_in_box_outside_row(
+    candidates: &mut [Vec<u32>],
+    board: &[Vec<u8>],
+    box_size: usize,
+    row: usize,
+    box_col: usize,
+    bit: u32,
+) -> Vec<(usize, usize)> {
+    let mut eliminated = Vec::new();
+    let box_row_start = (row / box_size) * box_size;
+    let box_col_start = box_col * box_size;
+    for r in box_row_start..box_row_start + box_size {
+        for c in box_col_start..box_col_start + box_size {
+            if r == row || board[r][c] != 0 {
+                continue;
+            }
+            let before = candidates[r][c];
+            candidates[r][c] &= !bit;
+            if candidates[r][c] != before {
+                eliminated.push((r, c));
+            }
+        }
+    }
+    eliminated
+}
+
+fn eliminate_in_box_outside_column(
+    candidates: &mut [Vec<u32>],
+    board: &[Vec<u8>],
+    box_size: usize,
+    col: usize,
+    box_row: usize,
+    bit: u32,
+) -> Vec<(usize, usize)> {
+    let mut eliminated = Vec::new();
+    let box_row_start = box_row * box_size;
+    let box_col_start = (col / box_size) * box_size;
+    for r in box_row_start..box_row_start + box_size {
+        for c in box_col_start..box_col_start + box_size {
+            if c == col || board[r][c] != 0 {
+                continue;
+            }
+            let before = candidates[r][c];
+            candidates[r][c] &= !bit;
+            if candidates[r][c] != before {
+                eliminated.push((r, c));
+            }
+        }
+    }
+    eliminated
+}
+
+// Every row, column, and box, tagged with the `Group` a step found there
+// should report.
+fn units(side: usize, box_size: usize) -> Vec<(Group, Vec<(usize, usize)>)> {
+    let mut units = Vec::new();
+
+    for i in 0..side {
+        units.push((Group::Row(i), (0..side).map(|j| (i, j)).collect()));
+    }
+    for j in 0..side {
+        units.push((Group::Column(j), (0..side).map(|i| (i, j)).collect()));
+    }
+    for bi in 0..box_size {
+        for bj in 0..box_size {
+            let cells = (0..box_size)
+                .flat_map(|x| (0..box_size).map(move |y| (bi * box_size + x, bj * box_size + y)))
+                .collect();
+            units.push((Group::Box(bi * box_size + bj), cells));
+        }
+    }
+
+    units
+}
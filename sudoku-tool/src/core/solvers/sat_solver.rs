@@ -0,0 +1,234 @@
+use crate::core::sudoku::Sudoku;
+use std::collections::BTreeSet;
+
+/*
+
+Encodes a Sudoku board as a CNF formula and solves it with DPLL, mirroring
+how sudoku_sat turns a grid into clauses before handing it to a SAT engine:
+
+  1. Boolean variable v(r, c, d) means "cell (r, c) holds digit d".
+  2. At-least-one: every cell holds at least one of its `side` digits.
+  3. At-most-one: every cell holds at most one digit (pairwise negative clauses).
+  4. Uniqueness: every digit appears at most once per row, column, and box
+     (same pairwise negative-clause shape as at-most-one, just over a
+     different set of cells).
+  5. Presets: each already-solved hint becomes a unit clause.
+
+Unit propagation plus naive backtracking (no clause learning) is enough to
+crack a standard puzzle quickly and gives users a second solver to reach
+for when `bf_solver`'s backtracking struggles on an adversarial instance.
+
+*/
+
+// A CNF literal: positive values assert the variable, negative values
+// assert its negation. Variables are numbered from 1, matching the
+// convention of most DIMACS-style SAT tooling.
+pub type Literal = i32;
+pub type Clause = Vec<Literal>;
+
+#[derive(Debug, Clone)]
+pub struct CnfFormula {
+    pub num_vars: usize,
+    pub clauses: Vec<Clause>,
+}
+
+// Maps (row, col, digit) to its boolean variable, 1-indexed so literals can
+// be negated by negating the integer.
+fn var(side: usize, row: usize, col: usize, digit: u8) -> Literal {
+    (row * side * side + col * side + (digit as usize - 1) + 1) as Literal
+}
+
+// Builds the CNF encoding of `sudoku`: every cell's digit choice, the
+// uniqueness constraints for rows/columns/boxes, and the preset hints.
+pub fn encode(sudoku: &Sudoku) -> CnfFormula {
+    let side = sudoku.side();
+    let box_size = sudoku.box_size;
+    let mut clauses = Vec::new();
+
+    // At-least-one and at-most-one per cell.
+    for r in 0..side {
+        for c in 0..side {
+            clauses.push((1..=side as u8).map(|d| var(side, r, c, d)).collect());
+            for d1 in 1..=side as u8 {
+                for d2 in (d1 + 1)..=side as u8 {
+                    clauses.push(vec![-var(side, r, c, d1), -var(side, r, c, d2)]);
+                }
+            }
+        }
+    }
+
+    // Uniqueness within each row, column, and box: no two cells in the same
+    // unit may both hold the same digit.
+    let units = rows_cols_boxes(side, box_size);
+    for unit in &units {
+        for d in 1..=side as u8 {
+            for i in 0..unit.len() {
+                for j in (i + 1)..unit.len() {
+                    let (r1, c1) = unit[i];
+                    let (r2, c2) = unit[j];
+                    clauses.push(vec![-var(side, r1, c1, d), -var(side, r2, c2, d)]);
+                }
+            }
+        }
+    }
+
+    // Presets: each solved hint is a unit clause pinning its variable true.
+    for r in 0..side {
+        for c in 0..side {
+            if let Some(value) = sudoku.get_solved_value(r, c) {
+                clauses.push(vec![var(side, r, c, value)]);
+            }
+        }
+    }
+
+    CnfFormula {
+        num_vars: side * side * side,
+        clauses,
+    }
+}
+
+// The cell groups ("units") that must each contain every digit exactly
+// once: every row, every column, and every box.
+fn rows_cols_boxes(side: usize, box_size: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut units = Vec::new();
+
+    for r in 0..side {
+        units.push((0..side).map(|c| (r, c)).collect());
+    }
+    for c in 0..side {
+        units.push((0..side).map(|r| (r, c)).collect());
+    }
+    for box_row in 0..box_size {
+        for box_col in 0..box_size {
+            let cells = (0..box_size)
+                .flat_map(|dr| (0..box_size).map(move |dc| (dr, dc)))
+                .map(|(dr, dc)| (box_row * box_size + dr, box_col * box_size + dc))
+                .collect();
+            units.push(cells);
+        }
+    }
+
+    units
+}
+
+// Solves `sudoku` via the CNF encoding and DPLL, returning the completed
+// board or `None` if the puzzle has no solution.
+pub fn solve(sudoku: &Sudoku) -> Option<Sudoku> {
+    let cnf = encode(sudoku);
+    let assignment = dpll(&cnf)?;
+    Some(decode(sudoku.box_size, sudoku.side(), &assignment))
+}
+
+// Rebuilds a `Sudoku` from a satisfying assignment: for each cell, the one
+// digit whose variable came out true becomes that cell's singleton set.
+fn decode(box_size: usize, side: usize, assignment: &[bool]) -> Sudoku {
+    let mut sudoku = Sudoku::new_with_box_size(box_size);
+    for r in 0..side {
+        for c in 0..side {
+            for d in 1..=side as u8 {
+                let v = var(side, r, c, d) as usize;
+                if assignment[v - 1] {
+                    sudoku.set_cell(r, c, d).unwrap();
+                    break;
+                }
+            }
+        }
+    }
+    sudoku
+}
+
+// Unit propagation plus backtracking search (DPLL). `assignment[v - 1]`
+// holds the current truth value of variable `v` once decided.
+fn dpll(cnf: &CnfFormula) -> Option<Vec<bool>> {
+    let mut assigned = vec![None; cnf.num_vars];
+    solve_from(&cnf.clauses, &mut assigned).map(|assigned| {
+        assigned
+            .into_iter()
+            .map(|value| value.unwrap_or(false))
+            .collect()
+    })
+}
+
+fn solve_from(clauses: &[Clause], assigned: &mut [Option<bool>]) -> Option<Vec<Option<bool>>> {
+    if !unit_propagate(clauses, assigned) {
+        return None;
+    }
+
+    let undecided = assigned.iter().position(|value| value.is_none());
+    let Some(var_idx) = undecided else {
+        return Some(assigned.to_vec());
+    };
+
+    for &guess in &[true, false] {
+        let mut trial = assigned.to_vec();
+        trial[var_idx] = Some(guess);
+        if let Some(result) = solve_from(clauses, &mut trial) {
+            return Some(result);
+        }
+    }
+
+    None
+}
+
+// Repeatedly finds clauses with exactly one unassigned literal and all
+// other literals falsified, and assigns that literal to satisfy the
+// clause. Returns `false` if propagation derives a contradiction (an
+// unsatisfiable clause under the current assignment).
+fn unit_propagate(clauses: &[Clause], assigned: &mut [Option<bool>]) -> bool {
+    loop {
+        let mut made_progress = false;
+
+        for clause in clauses {
+            let mut unassigned_literal = None;
+            let mut satisfied = false;
+            let mut unassigned_count = 0;
+
+            for &literal in clause {
+                match literal_value(literal, assigned) {
+                    Some(true) => {
+                        satisfied = true;
+                        break;
+                    }
+                    Some(false) => {}
+                    None => {
+                        unassigned_count += 1;
+                        unassigned_literal = Some(literal);
+                    }
+                }
+            }
+
+            if satisfied {
+                continue;
+            }
+            if unassigned_count == 0 {
+                return false; // every literal is false: contradiction
+            }
+            if unassigned_count == 1 {
+                let literal = unassigned_literal.unwrap();
+                let var_idx = literal.unsigned_abs() as usize - 1;
+                assigned[var_idx] = Some(literal > 0);
+                made_progress = true;
+            }
+        }
+
+        if !made_progress {
+            return true;
+        }
+    }
+}
+
+fn literal_value(literal: Literal, assigned: &[Option<bool>]) -> Option<bool> {
+    let var_idx = literal.unsigned_abs() as usize - 1;
+    assigned[var_idx].map(|value| if literal > 0 { value } else { !value })
+}
+
+// Collects the set of distinct variables referenced by a formula; useful
+// for sanity-checking the encoding in tests without hardcoding counts.
+#[allow(dead_code)]
+fn referenced_vars(cnf: &CnfFormula) -> BTreeSet<usize> {
+    cnf.clauses
+        .iter()
+        .flatten()
+        .map(|literal| literal.unsigned_abs() as usize)
+        .collect()
+}
@@ -1,13 +1,46 @@
-use std::collections::BTreeSet;
+use std::collections::HashSet;
 use std::fmt;
 use std::fs;
 use std::path::Path;
 
 use array2d::Array2D;
 
-#[derive(Debug)]
+// Per-cell candidate set, bit `d - 1` marking digit `d` as still possible.
+// A u32 covers every box order this crate generalizes to, up to and
+// including 25x25 (box_size 5, side 25 -- matching the occupancy masks
+// `core::solvers::bf_solver` already uses), in exchange for turning every
+// constraint-propagation loop into branch-light bit operations with no
+// per-cell heap allocation.
+pub type CandidateMask = u32;
+
+// The largest board side a `CandidateMask` can represent: one bit per
+// digit, so the mask width is the hard ceiling. `box_size_for_side` is the
+// single gate every public entry point routes through to enforce this.
+const MAX_SIDE: usize = CandidateMask::BITS as usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Sudoku {
-    pub grid: Array2D<BTreeSet<u8>>,
+    pub box_size: usize, // b: the board is b^2 x b^2, candidates range 1..=b^2
+    pub grid: Array2D<CandidateMask>,
+}
+
+// All `side` candidate bits set, i.e. the mask for a completely unsolved cell.
+fn full_mask(side: usize) -> CandidateMask {
+    debug_assert!(
+        side <= MAX_SIDE,
+        "{}-bit candidate masks only cover side <= {}",
+        CandidateMask::BITS,
+        MAX_SIDE
+    );
+    if side == MAX_SIDE {
+        CandidateMask::MAX
+    } else {
+        (1 << side) - 1
+    }
+}
+
+fn mask_for_value(value: u8) -> CandidateMask {
+    1 << (value - 1)
 }
 
 impl Default for Sudoku {
@@ -17,37 +50,63 @@ impl Default for Sudoku {
 }
 
 impl Sudoku {
+    // Classic 9x9 board (box_size = 3)
     pub fn new() -> Self {
+        Self::new_with_box_size(3)
+    }
+
+    // Alias for `new()`, for call sites that want to spell out that they
+    // mean the classic 9x9 board rather than some other order.
+    pub fn standard() -> Self {
+        Self::new()
+    }
+
+    // Board of box_size^2 x box_size^2 cells, e.g. box_size=4 for a 16x16 grid
+    pub fn new_with_box_size(box_size: usize) -> Self {
+        let side = box_size * box_size;
         Sudoku {
-            grid: Array2D::filled_with(BTreeSet::new(), 9, 9),
+            box_size,
+            grid: Array2D::filled_with(0, side, side),
         }
     }
 
-    // Create a preset board from a 2D array
-    pub fn from_preset(preset: [[Option<u8>; 9]; 9]) -> Self {
-        let mut sudoku = Sudoku::new();
+    pub fn side(&self) -> usize {
+        self.box_size * self.box_size
+    }
+
+    // Create a preset board from a square 2D array of hints. Panics on a
+    // malformed preset (bad side length), matching this constructor's
+    // existing contract for out-of-range hint values below -- callers that
+    // parse untrusted input should validate with `box_size_for_side` first
+    // (see `core::format`/`core::puzzle_parser`) rather than relying on this
+    // panic.
+    pub fn from_preset(preset: Vec<Vec<Option<u8>>>) -> Self {
+        let side = preset.len();
+        let box_size =
+            Self::box_size_for_side(side).unwrap_or_else(|e| panic!("Invalid preset: {}", e));
+        let mut sudoku = Sudoku::new_with_box_size(box_size);
 
         // Set the hints
         for (row_idx, row) in preset.iter().enumerate() {
             for (col_idx, &value) in row.iter().enumerate() {
                 // Check for valid value
                 if let Some(val) = value {
-                    if val < 1 || val > 9 {
+                    if val < 1 || val as usize > side {
                         panic!(
-                            "Invalid value {} at position ({}, {}) in preset. Values must be between 1-9.",
-                            val, row_idx, col_idx
+                            "Invalid value {} at position ({}, {}) in preset. Values must be between 1-{}.",
+                            val, row_idx, col_idx, side
                         );
                     }
 
                     // Set the cell to only contain this value (solved)
                     sudoku
                         .grid
-                        .set(row_idx, col_idx, BTreeSet::from([val]))
+                        .set(row_idx, col_idx, mask_for_value(val))
                         .unwrap();
                 } else {
                     sudoku
                         .grid
-                        .set(row_idx, col_idx, (1..=9).collect())
+                        .set(row_idx, col_idx, full_mask(side))
                         .unwrap();
                 }
             }
@@ -56,39 +115,43 @@ impl Sudoku {
         sudoku
     }
 
-    fn markup_empty_cells(&mut self) {
-        for row in 0..9 {
-            for col in 0..9 {
+    pub(crate) fn markup_empty_cells(&mut self) {
+        let side = self.side();
+        for row in 0..side {
+            for col in 0..side {
                 // If this cell is solved, remove its value from peers
-                if let Some(value) = self.get_solved_value(row, col) {
+                if self.get_solved_value(row, col).is_some() {
                     self.remove_value_from_peers(row, col);
                 }
             }
         }
     }
     // Remove a solved cell's value from all cells in same row, column, and box
-    fn remove_value_from_peers(&mut self, row: usize, col: usize) {
+    pub(crate) fn remove_value_from_peers(&mut self, row: usize, col: usize) {
         if let Some(solved_value) = self.get_solved_value(row, col) {
+            let side = self.side();
+            let b = self.box_size;
+
             // Remove from same row
-            for c in 0..9 {
+            for c in 0..side {
                 if c != col {
                     self.remove_possibility(row, c, solved_value);
                 }
             }
 
             // Remove from same column
-            for r in 0..9 {
+            for r in 0..side {
                 if r != row {
                     self.remove_possibility(r, col, solved_value);
                 }
             }
 
-            // Remove from same 3x3 box
-            let box_row_start = (row / 3) * 3;
-            let box_col_start = (col / 3) * 3;
+            // Remove from same box
+            let box_row_start = (row / b) * b;
+            let box_col_start = (col / b) * b;
 
-            for r in box_row_start..box_row_start + 3 {
-                for c in box_col_start..box_col_start + 3 {
+            for r in box_row_start..box_row_start + b {
+                for c in box_col_start..box_col_start + b {
                     if r != row || c != col {
                         self.remove_possibility(r, c, solved_value);
                     }
@@ -98,19 +161,27 @@ impl Sudoku {
     }
     // Check if a cell is solved and, if so, get the solved value of a cell
     pub fn get_solved_value(&self, row: usize, col: usize) -> Option<u8> {
-        let set = self.grid.get(row, col).unwrap();
-        if set.len() == 1 {
-            Some(*set.iter().next().unwrap())
+        let mask = *self.grid.get(row, col).unwrap();
+        if mask.count_ones() == 1 {
+            Some(mask.trailing_zeros() as u8 + 1)
         } else {
             None
         }
     }
+    // The raw candidate mask for a cell, solved or not. Exposed crate-wide
+    // (rather than `pub`) so solvers like `crook_solver` can inspect
+    // in-progress candidate sets without every caller needing bit-twiddling.
+    pub(crate) fn candidate_mask(&self, row: usize, col: usize) -> CandidateMask {
+        *self.grid.get(row, col).unwrap()
+    }
     // Remove a possibility from a cell
     pub fn remove_possibility(&mut self, row: usize, col: usize, value: u8) -> bool {
-        if let Some(set) = self.grid.get_mut(row, col) {
+        if let Some(mask) = self.grid.get_mut(row, col) {
+            let bit = mask_for_value(value);
             // Only remove from unsolved cells
-            if set.len() > 1 {
-                set.remove(&value)
+            if mask.count_ones() > 1 && *mask & bit != 0 {
+                *mask &= !bit;
+                true
             } else {
                 false
             }
@@ -120,46 +191,50 @@ impl Sudoku {
     }
     // Edit a single cell. Only called before calling solver.
     pub fn set_cell(&mut self, row: usize, col: usize, value: u8) -> Result<(), String> {
-        if row >= 9 || col >= 9 {
+        let side = self.side();
+        if row >= side || col >= side {
             return Err("Invalid cell position".to_string());
         }
 
-        if value < 1 || value > 9 {
-            return Err("Value must be between 1 and 9".to_string());
+        if value < 1 || value as usize > side {
+            return Err(format!("Value must be between 1 and {}", side));
         }
 
         self.grid
-            .set(row, col, BTreeSet::from([value]))
+            .set(row, col, mask_for_value(value))
             .map_err(|e| e.to_string())?;
         Ok(())
     }
 
     pub fn is_solved(&self) -> bool {
+        let side = self.side();
+        let b = self.box_size;
+
         // Check all rows
         for row in self.grid.rows_iter() {
-            if !Self::check_unit(row) {
+            if !Self::check_unit(row, side) {
                 return false;
             }
         }
 
         // Check all columns
         for col in self.grid.columns_iter() {
-            if !Self::check_unit(col) {
+            if !Self::check_unit(col, side) {
                 return false;
             }
         }
 
-        // Check all subgrids
-        for i in 0..3 {
-            // row index of each subgrid
-            for j in 0..3 {
-                // col index of each subgrid
-                let subgrid_iter = (0..3).flat_map(|x| (0..3).map(move |y| (i * 3 + x, j * 3 + y)));
+        // Check all boxes
+        for i in 0..b {
+            // row index of each box
+            for j in 0..b {
+                // col index of each box
+                let box_iter = (0..b).flat_map(|x| (0..b).map(move |y| (i * b + x, j * b + y)));
 
-                // gets cell values from subgrid_iter
-                let cells = subgrid_iter.map(|(r, c)| self.grid.get(r, c).unwrap());
+                // gets cell values from box_iter
+                let cells = box_iter.map(|(r, c)| self.grid.get(r, c).unwrap());
 
-                if !Self::check_unit(cells) {
+                if !Self::check_unit(cells, side) {
                     return false;
                 }
             }
@@ -168,53 +243,57 @@ impl Sudoku {
         true
     }
 
-    fn check_unit<'a, I>(unit: I) -> bool
+    fn check_unit<'a, I>(unit: I, side: usize) -> bool
     where
-        I: Iterator<Item = &'a BTreeSet<u8>>,
+        I: Iterator<Item = &'a CandidateMask>,
     {
-        // Track numbers 1-9 (index 1-9)
-        let mut seen = [false; 10];
+        // Track numbers 1..=side (index 1..=side)
+        let mut seen = vec![false; side + 1];
 
-        for cell in unit {
+        for &mask in unit {
             // Empty, or marked cell
-            if cell.len() != 1 {
+            if mask.count_ones() != 1 {
                 return false;
             }
 
-            let num = cell.iter().next().unwrap();
+            let num = mask.trailing_zeros() as usize + 1;
 
             // Duplicate number
-            if seen[*num as usize] {
+            if seen[num] {
                 return false;
             }
 
-            seen[*num as usize] = true;
+            seen[num] = true;
         }
 
-        // Check if all numbers 1-9 are present
-        seen[1..=9].iter().all(|&present| present)
+        // Check if all numbers 1..=side are present
+        seen[1..=side].iter().all(|&present| present)
     }
 }
 
 impl fmt::Display for Sudoku {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = self.side();
+        let b = self.box_size;
+        let width = side.to_string().len();
+
         for (i, row) in self.grid.rows_iter().enumerate() {
-            // Add horizontal separators every 3 rows
-            if i % 3 == 0 && i != 0 {
-                writeln!(f, "------+-------+------")?;
+            // Add horizontal separators every b rows
+            if i % b == 0 && i != 0 {
+                writeln!(f, "{}", "-".repeat((width + 1) * side + 2 * (b - 1)))?;
             }
 
             for (j, cell) in row.enumerate() {
-                // Add vertical separators every 3 columns
-                if j % 3 == 0 && j != 0 {
+                // Add vertical separators every b columns
+                if j % b == 0 && j != 0 {
                     write!(f, "| ")?;
                 }
 
                 // Print cell value or '_' for empty
                 if let Some(value) = self.get_solved_value(i, j) {
-                    write!(f, "{} ", value)?;
+                    write!(f, "{:>width$} ", value, width = width)?;
                 } else {
-                    write!(f, "_ ")?;
+                    write!(f, "{:>width$} ", "_", width = width)?;
                 }
             }
             writeln!(f)?;
@@ -225,102 +304,112 @@ impl fmt::Display for Sudoku {
 
 // For input file reading
 impl Sudoku {
+    // Tries every format `crate::core::puzzle_parser` knows how to read
+    // (flat string, line grid, box-divider grid) and keeps the first match.
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
         let content =
             fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
 
-        let mut preset = [[None; 9]; 9];
-        let mut row = 0;
-
-        for line in content.lines() {
-            // Skip empty lines
-            if line.trim().is_empty() {
-                continue;
-            }
+        crate::core::puzzle_parser::parse_puzzle(&content).map(|(sudoku, _format)| sudoku)
+    }
 
-            if row >= 9 {
-                return Err("Too many rows in file".to_string());
-            }
+    pub fn from_string(s: &str) -> Result<Self, String> {
+        crate::core::puzzle_parser::parse_puzzle(s).map(|(sudoku, _format)| sudoku)
+    }
 
-            let numbers: Vec<&str> = line.split_whitespace().collect();
-            if numbers.len() != 9 {
-                return Err(format!(
-                    "Row {} has {} numbers, expected 9",
-                    row + 1,
-                    numbers.len()
-                ));
-            }
+    /// Parses the flat single-line format directly, without trying the
+    /// line-grid/box-divider formats `from_string` also understands. See
+    /// `crate::core::format::from_str_line` for the exact grammar.
+    pub fn from_str_line(s: &str) -> Result<Self, String> {
+        crate::core::format::from_str_line(s)
+    }
 
-            for (col, num_str) in numbers.iter().enumerate() {
-                preset[row][col] = match *num_str {
-                    "_" => None,
-                    num => {
-                        let value = num.parse::<u8>().map_err(|_| {
-                            format!(
-                                "Invalid number '{}' at position ({}, {})",
-                                num,
-                                row + 1,
-                                col + 1
-                            )
-                        })?;
-                        if value < 1 || value > 9 {
-                            return Err(format!(
-                                "Number {} out of range 1-9 at position ({}, {})",
-                                value,
-                                row + 1,
-                                col + 1
-                            ));
-                        }
-                        Some(value)
-                    }
-                };
-            }
-            row += 1;
-        }
+    /// Parses the ksudoku-style `WxH:type:v,v,v,...` puzzle string. See
+    /// `crate::core::format::from_ksudoku` for the exact grammar.
+    pub fn from_ksudoku(s: &str) -> Result<Self, String> {
+        crate::core::format::from_ksudoku(s)
+    }
 
-        if row != 9 {
-            return Err("Not enough rows in file".to_string());
+    /// Parses the coordinate-triple format used by the Rust sudoku
+    /// benchmark: a header line `N,N` giving the side length, followed by
+    /// zero or more `row,col,value` lines (0-based coordinates, 1-based
+    /// digit; `0` or an omitted coordinate means empty). Unlike
+    /// `from_string`/`from_file`, hints don't need to cover every cell.
+    pub fn from_coords(s: &str) -> Result<Self, String> {
+        let mut lines = s.lines().map(str::trim).filter(|l| !l.is_empty());
+
+        let header = lines
+            .next()
+            .ok_or_else(|| "Missing header line".to_string())?;
+        let header_parts: Vec<&str> = header.split(',').collect();
+        if header_parts.len() != 2 {
+            return Err(format!(
+                "Invalid header '{}': expected 'N,N' giving the side length",
+                header
+            ));
         }
 
-        Ok(Sudoku::from_preset(preset))
-    }
-    pub fn from_string(s: &str) -> Result<Self, String> {
-        // Remove any whitespace and ensure we have exactly 81 characters
-        let cleaned: String = s.chars().filter(|c| !c.is_whitespace()).collect();
-
-        if cleaned.len() != 81 {
+        let side: usize = header_parts[0]
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid side length '{}' in header", header_parts[0]))?;
+        let side_check: usize = header_parts[1]
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid side length '{}' in header", header_parts[1]))?;
+        if side != side_check {
             return Err(format!(
-                "Invalid puzzle length: {} (expected 81 characters after removing whitespace). Original: '{}'",
-                cleaned.len(),
-                s
+                "Header side lengths disagree: {} vs {}",
+                side, side_check
             ));
         }
+        let _ = Self::box_size_for_side(side)?;
 
-        let mut preset = [[None; 9]; 9];
-        let mut chars = cleaned.chars();
+        let mut preset = vec![vec![None; side]; side];
+        let mut seen_coords = HashSet::new();
+
+        for line in lines {
+            let parts: Vec<&str> = line.split(',').collect();
+            if parts.len() != 3 {
+                return Err(format!(
+                    "Invalid coordinate line '{}': expected 'row,col,value'",
+                    line
+                ));
+            }
 
-        for row in 0..9 {
-            for col in 0..9 {
-                let ch = chars.next().unwrap();
-                let position = row * 9 + col + 1; // 1-based position for error messages
+            let row: usize = parts[0]
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid row '{}' in line '{}'", parts[0], line))?;
+            let col: usize = parts[1]
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid column '{}' in line '{}'", parts[1], line))?;
+            let value: u8 = parts[2]
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid value '{}' in line '{}'", parts[2], line))?;
+
+            if row >= side || col >= side {
+                return Err(format!(
+                    "Coordinate ({}, {}) out of range for a {}x{} board",
+                    row, col, side, side
+                ));
+            }
+            if !seen_coords.insert((row, col)) {
+                return Err(format!("Duplicate coordinate ({}, {}) in input", row, col));
+            }
 
-                preset[row][col] = match ch {
-                    '.' | '0' => None, // Empty cell
-                    '1'..='9' => {
-                        let value = ch.to_digit(10).unwrap() as u8;
-                        Some(value)
-                    }
-                    _ => {
-                        return Err(format!(
-                            "Invalid character '{}' at position {} (row {}, col {}). Only digits 1-9, '.', or '0' are allowed.",
-                            ch,
-                            position,
-                            row + 1,
-                            col + 1
-                        ));
-                    }
-                };
+            if value == 0 {
+                continue; // Explicit "empty" triple
             }
+            if value as usize > side {
+                return Err(format!(
+                    "Value {} out of range 1-{} at ({}, {})",
+                    value, side, row, col
+                ));
+            }
+            preset[row][col] = Some(value);
         }
 
         Ok(Sudoku::from_preset(preset))
@@ -328,12 +417,17 @@ impl Sudoku {
 
     /// Convert Sudoku back to string representation (using '.' for empty cells)
     pub fn to_string(&self) -> String {
-        let mut result = String::with_capacity(81);
+        let side = self.side();
+        let mut result = String::with_capacity(side * side);
 
-        for row in 0..9 {
-            for col in 0..9 {
+        for row in 0..side {
+            for col in 0..side {
                 if let Some(value) = self.get_solved_value(row, col) {
-                    result.push_str(&value.to_string());
+                    if value <= 9 {
+                        result.push_str(&value.to_string());
+                    } else {
+                        result.push((b'A' + (value - 10)) as char);
+                    }
                 } else {
                     result.push('.');
                 }
@@ -342,4 +436,39 @@ impl Sudoku {
 
         result
     }
+
+    /// Same rendering as `to_string`, named for symmetry with
+    /// `from_str_line` -- the format callers round-trip puzzles through
+    /// when piping to/from files rather than displaying them.
+    pub fn to_line_string(&self) -> String {
+        crate::core::format::to_line_string(self)
+    }
+
+    /// Renders the board with the same box-divider layout as the
+    /// `Display` impl, captured as an owned `String`.
+    pub fn to_grid_display(&self) -> String {
+        crate::core::format::to_grid_display(self)
+    }
+
+    // Maps a board side length (9, 16, 25, ...) to its box dimension.
+    // Shared with `core::format`'s parsers, which need the same check.
+    pub(crate) fn box_size_for_side(side: usize) -> Result<usize, String> {
+        // Bound-check before squaring: `side` comes straight from untrusted
+        // input in some callers (e.g. `from_ksudoku`'s dims string), and a
+        // huge value would overflow `box_size * box_size` below.
+        if side > MAX_SIDE {
+            return Err(format!(
+                "Board side {} exceeds the maximum representable side of {}",
+                side, MAX_SIDE
+            ));
+        }
+        let box_size = (side as f64).sqrt().round() as usize;
+        if box_size * box_size != side {
+            return Err(format!(
+                "Board side {} is not a perfect square; cannot infer box size",
+                side
+            ));
+        }
+        Ok(box_size)
+    }
 }
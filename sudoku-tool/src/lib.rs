@@ -1,8 +1,15 @@
 pub mod core {
     pub mod solvers {
         pub mod bf_solver;
+        pub mod constraints;
+        pub mod crook_solver;
+        pub mod logic_solver;
+        pub mod sat_solver;
         // Add other solver modules as they become public
     }
+    pub mod format;
+    pub mod generator;
+    pub mod puzzle_parser;
     pub mod sudoku;
     // Add other core modules as needed
 }
@@ -1,12 +1,11 @@
-mod bf_solver;
-mod sudoku;
-
-use bf_solver::{SolverStats, find_all_solutions, find_one_solution};
 use std::fs;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
-use sudoku::Sudoku;
+use sudoku_tool::core::solvers::bf_solver::{
+    SearchStrategy, SolverStats, find_all_solutions, find_one_solution,
+};
+use sudoku_tool::core::sudoku::Sudoku;
 
 #[derive(Debug)]
 struct TestConfig {
@@ -130,7 +129,8 @@ fn test_puzzles_all_solutions(puzzle_files: &[String]) -> Vec<PuzzleResult> {
 
         match Sudoku::from_file(file_path) {
             Ok(puzzle) => {
-                let (solutions, stats) = find_all_solutions(&puzzle);
+                let (solutions, stats) =
+                    find_all_solutions(&puzzle, SearchStrategy::Default, None);
 
                 results.push(PuzzleResult {
                     filename: file_path.clone(),
@@ -165,8 +165,6 @@ fn test_puzzles_all_solutions(puzzle_files: &[String]) -> Vec<PuzzleResult> {
 }
 
 fn compare_solver_modes(puzzle_files: &[String]) -> Vec<(PuzzleResult, PuzzleResult)> {
-    todo!("implement this?");
-
     let mut comparisons = Vec::new();
 
     for file_path in puzzle_files {
@@ -175,7 +173,8 @@ fn compare_solver_modes(puzzle_files: &[String]) -> Vec<(PuzzleResult, PuzzleRes
         match Sudoku::from_file(file_path) {
             Ok(puzzle) => {
                 let (single_solution, single_stats) = find_one_solution(&puzzle);
-                let (all_solutions, all_stats) = find_all_solutions(&puzzle);
+                let (all_solutions, all_stats) =
+                    find_all_solutions(&puzzle, SearchStrategy::Default, None);
 
                 let single_result = PuzzleResult {
                     filename: file_path.clone(),
@@ -189,8 +188,6 @@ fn compare_solver_modes(puzzle_files: &[String]) -> Vec<(PuzzleResult, PuzzleRes
                     stats: all_stats,
                 };
 
-                comparisons.push((single_result, all_result));
-
                 // Print comparison summary
                 println!(
                     "  Single mode: {} nodes, {} solutions",
@@ -208,13 +205,27 @@ fn compare_solver_modes(puzzle_files: &[String]) -> Vec<(PuzzleResult, PuzzleRes
                     if let (Some(single_sol), Some(all_sol)) =
                         (single_result.first_solution(), all_result.first_solution())
                     {
-                        // if single_sol != all_sol {
-                        //     println!("  WARNING: Solutions differ between modes!");
-                        // } else {
-                        //     println!("  ✓ Solutions match between modes");
-                        // }
+                        if single_sol != all_sol {
+                            println!("  WARNING: Solutions differ between modes!");
+                        } else {
+                            println!("  ✓ Solutions match between modes");
+                        }
                     }
                 }
+
+                // A uniqueness bug: single mode stops at the first solution it
+                // finds, so it always reports exactly one even when the
+                // puzzle is under-constrained. If all-mode then turns up more
+                // than one, the puzzle doesn't actually have a unique
+                // solution.
+                if single_result.solution_count() == 1 && all_result.solution_count() > 1 {
+                    println!(
+                        "  WARNING: Puzzle reported as single-solution but all-mode found {} solutions!",
+                        all_result.solution_count()
+                    );
+                }
+
+                comparisons.push((single_result, all_result));
             }
             Err(e) => {
                 eprintln!("Error loading {}: {}", file_path, e);
@@ -225,6 +236,51 @@ fn compare_solver_modes(puzzle_files: &[String]) -> Vec<(PuzzleResult, PuzzleRes
     comparisons
 }
 
+fn export_comparison_to_csv(
+    comparisons: &[(PuzzleResult, PuzzleResult)],
+    filename: &str,
+) -> Result<(), std::io::Error> {
+    let mut file = File::create(filename)?;
+
+    // CSV header
+    writeln!(
+        &mut file,
+        "Puzzle,SingleNodesExplored,SingleBacktracks,SingleLeaves,SingleMaxRecursionDepth,SingleSearchDurationMicros,SingleSolutionCount,AllNodesExplored,AllBacktracks,AllLeaves,AllMaxRecursionDepth,AllSearchDurationMicros,AllSolutionCount,SolutionsMatch,UniquenessBug"
+    )?;
+
+    for (single_result, all_result) in comparisons {
+        let solutions_match = match (single_result.first_solution(), all_result.first_solution()) {
+            (Some(single_sol), Some(all_sol)) => single_sol == all_sol,
+            _ => false,
+        };
+        let uniqueness_bug =
+            single_result.solution_count() == 1 && all_result.solution_count() > 1;
+
+        writeln!(
+            &mut file,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            single_result.filename,
+            single_result.stats.nodes_explored,
+            single_result.stats.backtracks,
+            single_result.stats.leaves,
+            single_result.stats.max_recursion_depth,
+            single_result.stats.search_duration.as_micros(),
+            single_result.solution_count(),
+            all_result.stats.nodes_explored,
+            all_result.stats.backtracks,
+            all_result.stats.leaves,
+            all_result.stats.max_recursion_depth,
+            all_result.stats.search_duration.as_micros(),
+            all_result.solution_count(),
+            if solutions_match { "YES" } else { "NO" },
+            if uniqueness_bug { "YES" } else { "NO" },
+        )?;
+    }
+
+    println!("Comparison results exported to: {}", filename);
+    Ok(())
+}
+
 fn export_results_to_csv(results: &[PuzzleResult], filename: &str) -> Result<(), std::io::Error> {
     let mut file = File::create(filename)?;
 
@@ -289,27 +345,34 @@ fn main() {
     export_results_to_csv(&multi_results, "multiple_solution_results.csv").unwrap();
 
     // Compare solver modes
-    // println!("\nCOMPARING SOLVER MODES");
-    // println!("======================");
-    // let comparison_puzzles = scan_directory_for_puzzles(&config.comparison_dir);
-    // let comparisons = compare_solver_modes(&comparison_puzzles);
-    // export_comparison_to_csv(&comparisons, "solver_mode_comparison.csv").unwrap();
+    println!("\nCOMPARING SOLVER MODES");
+    println!("======================");
+    let comparison_puzzles = scan_directory_for_puzzles(&config.comparison_dir);
+    let comparisons = compare_solver_modes(&comparison_puzzles);
+    export_comparison_to_csv(&comparisons, "solver_mode_comparison.csv").unwrap();
 
     // Summary
     println!("\nSUMMARY");
     println!("=======");
     println!("Single solution puzzles tested: {}", single_results.len());
     println!("Multiple solution puzzles tested: {}", multi_results.len());
-    // println!("Comparison puzzles tested: {}", comparisons.len());
-
-    // Print any puzzles with multiple solutions found in single mode (shouldn't happen for proper Sudoku)
-    // let multi_in_single: Vec<_> = single_results.iter()
-    //     .filter(|r| r.solution_count > 1)
-    //     .collect();
-    // if !multi_in_single.is_empty() {
-    //     println!("\nWARNING: Found puzzles with multiple solutions in single-solution directory:");
-    //     for result in multi_in_single {
-    //         println!("  {}: {} solutions", result.filename, result.solution_count);
-    //     }
-    // }
+    println!("Comparison puzzles tested: {}", comparisons.len());
+
+    // Flag comparison puzzles that are supposed to have a unique solution
+    // but don't: single mode reports exactly one solution, while all-mode
+    // (which doesn't stop early) finds more than one.
+    let uniqueness_bugs: Vec<_> = comparisons
+        .iter()
+        .filter(|(single, all)| single.solution_count() == 1 && all.solution_count() > 1)
+        .collect();
+    if !uniqueness_bugs.is_empty() {
+        println!("\nWARNING: Found puzzles reported as single-solution but with more solutions:");
+        for (single, all) in uniqueness_bugs {
+            println!(
+                "  {}: all-mode found {} solutions",
+                single.filename,
+                all.solution_count()
+            );
+        }
+    }
 }
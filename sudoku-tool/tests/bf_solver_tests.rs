@@ -26,7 +26,7 @@ fn test_solve_already_solved_puzzle() {
         [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(9)],
     ];
 
-    let puzzle = Sudoku::from_preset(preset);
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
 
     let (solution, stats) = find_one_solution(&puzzle);
     assert!(
@@ -54,15 +54,15 @@ fn test_shultz_301_all_strategies() {
         [None,    None,    None,    None,    None,     Some(1),  None,     None,     Some(8) ],
     ];
 
-    let puzzle = Sudoku::from_preset(preset);
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
 
     // Test all strategies
     let strategies = [
         ("Default", SearchStrategy::Default),
         ("Row/Col Random", SearchStrategy::RowColRandom),
-        ("Custom Row/Col", SearchStrategy::CustomRowCol { 
-            row_order: [2, 5, 1, 6, 3, 7, 4, 8, 0], 
-            col_order: [6, 8, 3, 4, 2, 0, 7, 5, 1] 
+        ("Custom Row/Col", SearchStrategy::CustomRowCol {
+            row_order: vec![2, 5, 1, 6, 3, 7, 4, 8, 0],
+            col_order: vec![6, 8, 3, 4, 2, 0, 7, 5, 1]
         }),
     ];
 
@@ -95,7 +95,7 @@ fn test_cell_order_strategies_simple() {
         [Some(9), Some(1), Some(2), Some(3), Some(4), Some(5), Some(6), Some(7), None   ],
     ];
 
-    let puzzle = Sudoku::from_preset(preset);
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
 
     // Test cell-based strategies on simple puzzle
     let strategies = [
@@ -153,7 +153,7 @@ fn test_wrapper_functions_fast() {
         [None,    None,    None,    None,    None,     Some(1),  None,     None,     Some(8) ],
     ];
 
-    let puzzle = Sudoku::from_preset(preset);
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
 
     // Test the fast wrapper functions (excluding random cell order)
     let (solution1, _) = find_one_solution(&puzzle);
@@ -163,8 +163,8 @@ fn test_wrapper_functions_fast() {
     assert!(solution2.is_some(), "Row/Col random wrapper should work");
 
     // Test custom row/col wrapper
-    let row_order = [0, 1, 2, 3, 4, 5, 6, 7, 8];
-    let col_order = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+    let row_order = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+    let col_order = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
     let (solution3, _) = find_one_solution_custom_rowcol_order(&puzzle, row_order, col_order);
     assert!(solution3.is_some(), "Custom row/col wrapper should work");
 
@@ -201,7 +201,7 @@ fn test_wrapper_function_random_cell_order() {
         [None,    None,    None,    None,    None,     Some(1),  None,     None,     Some(8) ],
     ];
 
-    let puzzle = Sudoku::from_preset(preset);
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
 
     println!("Testing random cell order wrapper (this may take a while)...");
     let (solution, stats) = find_one_solution_rand_cell_order(&puzzle);
@@ -228,7 +228,7 @@ fn test_mepham_d() {
         [None,    Some(5), Some(4), None,    None,     Some(8),  None,    Some(7), None    ],
     ];
 
-    let puzzle = Sudoku::from_preset(preset);
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
 
     let (solution, stats) = find_one_solution(&puzzle);
     assert!(solution.is_some(), "Puzzle should have a solution");
@@ -242,9 +242,10 @@ fn test_mepham_d() {
 
 #[test]
 fn test_is_safe_function() {
-    let mut rows = [0u16; 9];
-    let mut cols = [0u16; 9];
-    let mut subgrids = [0u16; 9];
+    let box_size = 3;
+    let mut rows = vec![0u32; 9];
+    let mut cols = vec![0u32; 9];
+    let mut subgrids = vec![0u32; 9];
 
     // Place number 5 at position (0,0)
     rows[0] |= 1 << 5;
@@ -252,12 +253,12 @@ fn test_is_safe_function() {
     subgrids[0] |= 1 << 5;
 
     // Should not be safe to place 5 again in same row/col/subgrid
-    assert!(!is_safe(&rows, &cols, &subgrids, 0, 1, 5));
-    assert!(!is_safe(&rows, &cols, &subgrids, 1, 0, 5));
-    assert!(!is_safe(&rows, &cols, &subgrids, 1, 1, 5));
+    assert!(!is_safe(box_size, &rows, &cols, &subgrids, 0, 1, 5));
+    assert!(!is_safe(box_size, &rows, &cols, &subgrids, 1, 0, 5));
+    assert!(!is_safe(box_size, &rows, &cols, &subgrids, 1, 1, 5));
 
     // Should be safe to place different number
-    assert!(is_safe(&rows, &cols, &subgrids, 0, 1, 6));
+    assert!(is_safe(box_size, &rows, &cols, &subgrids, 0, 1, 6));
 }
 
 #[test]
@@ -280,6 +281,251 @@ fn test_tree_width_tracking() {
     println!("Tree width tracking test passed with {} total nodes", stats.nodes_explored);
 }
 
+#[test]
+fn test_mrv_strategy_solves_puzzle() {
+    #[rustfmt::skip]
+    let preset = [
+        [None,    Some(3), Some(9), Some(5), None,     None,     None,     None,     None    ],
+        [None,    None,    None,    Some(8), None,     None,     None,     Some(7),  None    ],
+        [None,    None,    None,    None,    Some(1),  None,     Some(9),  None,     Some(4) ],
+        [Some(1), None,    None,    Some(4), None,     None,     None,     None,     Some(3) ],
+        [None,    None,    None,    None,    None,     None,     None,     None,     None    ],
+        [None,    None,    Some(7), None,    None,     None,     Some(8),  Some(6),  None    ],
+        [None,    None,    Some(6), Some(7), None,     Some(8),  Some(2),  None,     None    ],
+        [None,    Some(1), None,    None,    Some(9),  None,     None,     None,     Some(5) ],
+        [None,    None,    None,    None,    None,     Some(1),  None,     None,     Some(8) ],
+    ];
+
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    let (solution, stats) = find_one_solution_mrv_order(&puzzle);
+    assert!(solution.is_some(), "MRV strategy should find a solution");
+    assert!(solution.unwrap().is_solved());
+
+    let total_nodes: usize = stats.tree_width_by_level.iter().sum();
+    assert_eq!(total_nodes, stats.nodes_explored);
+}
+
+#[test]
+fn test_mrv_matches_default_on_already_solved_puzzle() {
+    #[rustfmt::skip]
+    let preset = [
+        [Some(5), Some(3), Some(4), Some(6), Some(7), Some(8), Some(9), Some(1), Some(2)],
+        [Some(6), Some(7), Some(2), Some(1), Some(9), Some(5), Some(3), Some(4), Some(8)],
+        [Some(1), Some(9), Some(8), Some(3), Some(4), Some(2), Some(5), Some(6), Some(7)],
+        [Some(8), Some(5), Some(9), Some(7), Some(6), Some(1), Some(4), Some(2), Some(3)],
+        [Some(4), Some(2), Some(6), Some(8), Some(5), Some(3), Some(7), Some(9), Some(1)],
+        [Some(7), Some(1), Some(3), Some(9), Some(2), Some(4), Some(8), Some(5), Some(6)],
+        [Some(9), Some(6), Some(1), Some(5), Some(3), Some(7), Some(2), Some(8), Some(4)],
+        [Some(2), Some(8), Some(7), Some(4), Some(1), Some(9), Some(6), Some(3), Some(5)],
+        [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(9)],
+    ];
+
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    let (solution, stats) = find_one_solution_mrv_order(&puzzle);
+    assert!(solution.is_some());
+    assert_eq!(stats.nodes_explored, 0, "Already-solved board has no empty cell to branch on");
+
+    let (_, default_stats) = find_one_solution(&puzzle);
+    assert_eq!(stats.nodes_explored, default_stats.nodes_explored);
+}
+
+#[test]
+fn test_propagate_strategy_solves_puzzle() {
+    #[rustfmt::skip]
+    let preset = [
+        [None,    Some(3), Some(9), Some(5), None,     None,     None,     None,     None    ],
+        [None,    None,    None,    Some(8), None,     None,     None,     Some(7),  None    ],
+        [None,    None,    None,    None,    Some(1),  None,     Some(9),  None,     Some(4) ],
+        [Some(1), None,    None,    Some(4), None,     None,     None,     None,     Some(3) ],
+        [None,    None,    None,    None,    None,     None,     None,     None,     None    ],
+        [None,    None,    Some(7), None,    None,     None,     Some(8),  Some(6),  None    ],
+        [None,    None,    Some(6), Some(7), None,     Some(8),  Some(2),  None,     None    ],
+        [None,    Some(1), None,    None,    Some(9),  None,     None,     None,     Some(5) ],
+        [None,    None,    None,    None,    None,     Some(1),  None,     None,     Some(8) ],
+    ];
+
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    let (solution, stats) = find_one_solution_propagate(&puzzle);
+    assert!(solution.is_some(), "Propagate strategy should find a solution");
+    assert!(solution.unwrap().is_solved());
+    assert!(
+        stats.assignments_by_propagation > 0,
+        "Naked/hidden singles should place at least one cell before branching"
+    );
+}
+
+#[test]
+fn test_propagate_strategy_solves_already_solved_puzzle_without_search() {
+    #[rustfmt::skip]
+    let preset = [
+        [Some(5), Some(3), Some(4), Some(6), Some(7), Some(8), Some(9), Some(1), Some(2)],
+        [Some(6), Some(7), Some(2), Some(1), Some(9), Some(5), Some(3), Some(4), Some(8)],
+        [Some(1), Some(9), Some(8), Some(3), Some(4), Some(2), Some(5), Some(6), Some(7)],
+        [Some(8), Some(5), Some(9), Some(7), Some(6), Some(1), Some(4), Some(2), Some(3)],
+        [Some(4), Some(2), Some(6), Some(8), Some(5), Some(3), Some(7), Some(9), Some(1)],
+        [Some(7), Some(1), Some(3), Some(9), Some(2), Some(4), Some(8), Some(5), Some(6)],
+        [Some(9), Some(6), Some(1), Some(5), Some(3), Some(7), Some(2), Some(8), Some(4)],
+        [Some(2), Some(8), Some(7), Some(4), Some(1), Some(9), Some(6), Some(3), Some(5)],
+        [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(9)],
+    ];
+
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    let (solution, stats) = find_one_solution_propagate(&puzzle);
+    assert!(solution.is_some());
+    assert_eq!(stats.assignments_by_search, 0, "Already-solved board needs no branching");
+}
+
+#[test]
+fn test_seeded_rowcol_order_is_reproducible() {
+    #[rustfmt::skip]
+    let preset = [
+        [None,    Some(3), Some(9), Some(5), None,     None,     None,     None,     None    ],
+        [None,    None,    None,    Some(8), None,     None,     None,     Some(7),  None    ],
+        [None,    None,    None,    None,    Some(1),  None,     Some(9),  None,     Some(4) ],
+        [Some(1), None,    None,    Some(4), None,     None,     None,     None,     Some(3) ],
+        [None,    None,    None,    None,    None,     None,     None,     None,     None    ],
+        [None,    None,    Some(7), None,    None,     None,     Some(8),  Some(6),  None    ],
+        [None,    None,    Some(6), Some(7), None,     Some(8),  Some(2),  None,     None    ],
+        [None,    Some(1), None,    None,    Some(9),  None,     None,     None,     Some(5) ],
+        [None,    None,    None,    None,    None,     Some(1),  None,     None,     Some(8) ],
+    ];
+
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    let (solution_a, stats_a) = find_one_solution_rand_rowcol_order_seeded(&puzzle, 42);
+    let (solution_b, stats_b) = find_one_solution_rand_rowcol_order_seeded(&puzzle, 42);
+
+    assert_eq!(solution_a.unwrap().to_string(), solution_b.unwrap().to_string());
+    assert_eq!(stats_a.nodes_explored, stats_b.nodes_explored);
+}
+
+#[test]
+fn test_seeded_cell_order_is_reproducible() {
+    #[rustfmt::skip]
+    let preset = [
+        [None,    Some(3), Some(9), Some(5), None,     None,     None,     None,     None    ],
+        [None,    None,    None,    Some(8), None,     None,     None,     Some(7),  None    ],
+        [None,    None,    None,    None,    Some(1),  None,     Some(9),  None,     Some(4) ],
+        [Some(1), None,    None,    Some(4), None,     None,     None,     None,     Some(3) ],
+        [None,    None,    None,    None,    None,     None,     None,     None,     None    ],
+        [None,    None,    Some(7), None,    None,     None,     Some(8),  Some(6),  None    ],
+        [None,    None,    Some(6), Some(7), None,     Some(8),  Some(2),  None,     None    ],
+        [None,    Some(1), None,    None,    Some(9),  None,     None,     None,     Some(5) ],
+        [None,    None,    None,    None,    None,     Some(1),  None,     None,     Some(8) ],
+    ];
+
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    let (solution_a, stats_a) = find_one_solution_rand_cell_order_seeded(&puzzle, 7);
+    let (solution_b, stats_b) = find_one_solution_rand_cell_order_seeded(&puzzle, 7);
+
+    assert_eq!(solution_a.unwrap().to_string(), solution_b.unwrap().to_string());
+    assert_eq!(stats_a.nodes_explored, stats_b.nodes_explored);
+}
+
+#[test]
+fn test_count_solutions_upto_stops_at_cap() {
+    // An empty board has many solutions; a cap of 2 should stop well short of them all.
+    let puzzle = Sudoku::new();
+    let (count, stats) = count_solutions_upto(&puzzle, 2);
+
+    assert_eq!(count, 2);
+    assert_eq!(stats.solutions_found, 2);
+}
+
+#[test]
+fn test_has_unique_solution() {
+    #[rustfmt::skip]
+    let solved_preset = [
+        [Some(5), Some(3), Some(4), Some(6), Some(7), Some(8), Some(9), Some(1), Some(2)],
+        [Some(6), Some(7), Some(2), Some(1), Some(9), Some(5), Some(3), Some(4), Some(8)],
+        [Some(1), Some(9), Some(8), Some(3), Some(4), Some(2), Some(5), Some(6), Some(7)],
+        [Some(8), Some(5), Some(9), Some(7), Some(6), Some(1), Some(4), Some(2), Some(3)],
+        [Some(4), Some(2), Some(6), Some(8), Some(5), Some(3), Some(7), Some(9), Some(1)],
+        [Some(7), Some(1), Some(3), Some(9), Some(2), Some(4), Some(8), Some(5), Some(6)],
+        [Some(9), Some(6), Some(1), Some(5), Some(3), Some(7), Some(2), Some(8), Some(4)],
+        [Some(2), Some(8), Some(7), Some(4), Some(1), Some(9), Some(6), Some(3), Some(5)],
+        [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(9)],
+    ];
+    let solved = Sudoku::from_preset(solved_preset.iter().map(|r| r.to_vec()).collect());
+    assert!(has_unique_solution(&solved));
+
+    let empty = Sudoku::new();
+    assert!(!has_unique_solution(&empty), "Empty board has many solutions");
+}
+
+#[test]
+fn test_find_all_solutions_stops_at_max() {
+    // An empty board has many solutions; capping at 3 should stop well short of them all.
+    let puzzle = Sudoku::new();
+    let (solutions, stats) = find_all_solutions(&puzzle, SearchStrategy::Default, Some(3));
+
+    assert_eq!(solutions.len(), 3);
+    assert_eq!(stats.solutions_found, 3);
+    for solution in &solutions {
+        assert!(solution.is_solved());
+    }
+}
+
+#[test]
+fn test_find_all_solutions_on_uniquely_solvable_puzzle() {
+    #[rustfmt::skip]
+    let preset = [
+        [Some(5), Some(3), None,    None,    Some(7), None,    None,    None,    None   ],
+        [Some(6), None,    None,    Some(1), Some(9), Some(5), None,    None,    None   ],
+        [None,    Some(9), Some(8), None,    None,    None,    None,    Some(6), None   ],
+        [Some(8), None,    None,    None,    Some(6), None,    None,    None,    Some(3)],
+        [Some(4), None,    None,    Some(8), None,    Some(3), None,    None,    Some(1)],
+        [Some(7), None,    None,    None,    Some(2), None,    None,    None,    Some(6)],
+        [None,    Some(6), None,    None,    None,    None,    Some(2), Some(8), None   ],
+        [None,    None,    None,    Some(4), Some(1), Some(9), None,    None,    Some(5)],
+        [None,    None,    None,    None,    Some(8), None,    None,    Some(7), Some(9)],
+    ];
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    let (solutions, stats) = find_all_solutions(&puzzle, SearchStrategy::Default, None);
+
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(stats.solutions_found, 1);
+    assert!(solutions[0].is_solved());
+    // Enumeration should have kept exploring past the first hit, not
+    // reset its tree-width accounting per solution.
+    assert!(stats.nodes_explored > 0);
+    let (_, single_stats) = find_one_solution(&puzzle);
+    assert!(stats.nodes_explored >= single_stats.nodes_explored);
+    assert!(stats.is_tree_data_consistent());
+}
+
+#[test]
+fn test_solve_16x16_puzzle() {
+    let puzzle = Sudoku::new_with_box_size(4);
+    let (solution, stats) = find_one_solution(&puzzle);
+
+    assert!(solution.is_some(), "Empty 16x16 puzzle should have a solution");
+    assert_eq!(stats.tree_width_by_level.len(), 16 * 16);
+
+    if let Some(solved_puzzle) = solution {
+        assert!(solved_puzzle.is_solved());
+    }
+}
+
+#[test]
+fn test_solve_4x4_puzzle() {
+    let puzzle = Sudoku::new_with_box_size(2);
+    let (solution, stats) = find_one_solution(&puzzle);
+
+    assert!(solution.is_some(), "Empty 4x4 puzzle should have a solution");
+    assert_eq!(stats.tree_width_by_level.len(), 4 * 4);
+
+    if let Some(solved_puzzle) = solution {
+        assert!(solved_puzzle.is_solved());
+    }
+}
+
 #[test]
 fn test_strategy_performance_comparison() {
     // Use a KNOWN solvable, medium difficulty puzzle
@@ -296,7 +542,7 @@ fn test_strategy_performance_comparison() {
         [None,    None,    Some(5), None,    Some(1), None,    Some(3), None,    None],
     ];
 
-    let puzzle = Sudoku::from_preset(preset);
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
 
     // First, verify the puzzle is valid and solvable with default strategy
     println!("\n=== Verifying puzzle is solvable ===");
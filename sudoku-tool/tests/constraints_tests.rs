@@ -0,0 +1,82 @@
+use sudoku_tool::core::solvers::bf_solver::{SearchStrategy, find_one_solution_with_constraints};
+use sudoku_tool::core::solvers::constraints::{
+    AntiKnightConstraint, CompositeConstraint, DefaultConstraint, DiagonalConstraint,
+    classic_constraints,
+};
+use sudoku_tool::core::sudoku::Sudoku;
+
+fn is_valid_classic_solution(solution: &Sudoku) -> bool {
+    let side = solution.side();
+    for i in 0..side {
+        for j in 0..side {
+            if solution.get_solved_value(i, j).is_none() {
+                return false;
+            }
+        }
+    }
+    solution.is_solved()
+}
+
+#[test]
+fn test_classic_constraints_solves_empty_board() {
+    let puzzle = Sudoku::new();
+    let constraint = classic_constraints(3);
+    let (solution, stats) =
+        find_one_solution_with_constraints(&puzzle, SearchStrategy::Default, &constraint);
+
+    let solution = solution.expect("empty board should be solvable");
+    assert!(is_valid_classic_solution(&solution));
+    assert_eq!(stats.solutions_found, 1);
+}
+
+#[test]
+fn test_diagonal_constraint_solution_respects_both_diagonals() {
+    let puzzle = Sudoku::new();
+    let constraint = CompositeConstraint::new(vec![
+        Box::new(DefaultConstraint::new(3)),
+        Box::new(DiagonalConstraint::new()),
+    ]);
+    let (solution, _) =
+        find_one_solution_with_constraints(&puzzle, SearchStrategy::Default, &constraint);
+
+    let solution = solution.expect("X-sudoku should be solvable on an empty board");
+    assert!(is_valid_classic_solution(&solution));
+
+    let main_diag: Vec<u8> = (0..9)
+        .map(|i| solution.get_solved_value(i, i).unwrap())
+        .collect();
+    let anti_diag: Vec<u8> = (0..9)
+        .map(|i| solution.get_solved_value(i, 8 - i).unwrap())
+        .collect();
+    for digit in 1..=9 {
+        assert_eq!(main_diag.iter().filter(|&&v| v == digit).count(), 1);
+        assert_eq!(anti_diag.iter().filter(|&&v| v == digit).count(), 1);
+    }
+}
+
+#[test]
+fn test_anti_knight_constraint_solution_has_no_knight_conflicts() {
+    let puzzle = Sudoku::new();
+    let constraint = CompositeConstraint::new(vec![
+        Box::new(DefaultConstraint::new(3)),
+        Box::new(AntiKnightConstraint::new()),
+    ]);
+    let (solution, _) =
+        find_one_solution_with_constraints(&puzzle, SearchStrategy::Default, &constraint);
+
+    let solution = solution.expect("anti-knight sudoku should be solvable on an empty board");
+    assert!(is_valid_classic_solution(&solution));
+
+    const KNIGHT_OFFSETS: [(i64, i64); 4] = [(-2, -1), (-2, 1), (-1, -2), (-1, 2)];
+    for row in 0..9 {
+        for col in 0..9 {
+            let val = solution.get_solved_value(row, col).unwrap();
+            for (dr, dc) in KNIGHT_OFFSETS {
+                let (nr, nc) = (row as i64 + dr, col as i64 + dc);
+                if nr >= 0 && nc >= 0 && (nr as usize) < 9 && (nc as usize) < 9 {
+                    assert_ne!(solution.get_solved_value(nr as usize, nc as usize), Some(val));
+                }
+            }
+        }
+    }
+}
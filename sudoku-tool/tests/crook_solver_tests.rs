@@ -0,0 +1,48 @@
+use sudoku_tool::core::solvers::crook_solver::CrookSolver;
+use sudoku_tool::core::sudoku::Sudoku;
+
+#[test]
+fn test_solve_already_solved_puzzle() {
+    #[rustfmt::skip]
+    let preset = [
+        [Some(5), Some(3), Some(4), Some(6), Some(7), Some(8), Some(9), Some(1), Some(2)],
+        [Some(6), Some(7), Some(2), Some(1), Some(9), Some(5), Some(3), Some(4), Some(8)],
+        [Some(1), Some(9), Some(8), Some(3), Some(4), Some(2), Some(5), Some(6), Some(7)],
+        [Some(8), Some(5), Some(9), Some(7), Some(6), Some(1), Some(4), Some(2), Some(3)],
+        [Some(4), Some(2), Some(6), Some(8), Some(5), Some(3), Some(7), Some(9), Some(1)],
+        [Some(7), Some(1), Some(3), Some(9), Some(2), Some(4), Some(8), Some(5), Some(6)],
+        [Some(9), Some(6), Some(1), Some(5), Some(3), Some(7), Some(2), Some(8), Some(4)],
+        [Some(2), Some(8), Some(7), Some(4), Some(1), Some(9), Some(6), Some(3), Some(5)],
+        [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(9)],
+    ];
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    let mut solver = CrookSolver::new(puzzle);
+    let stats = solver.solve();
+
+    assert_eq!(stats.solutions_found, 1);
+    assert!(solver.puzzle.is_solved());
+}
+
+#[test]
+fn test_solve_partial_puzzle_via_rules_or_backtracking() {
+    #[rustfmt::skip]
+    let preset = [
+        [None,    Some(3), Some(9), Some(5), None,     None,     None,     None,     None    ],
+        [None,    None,    None,    Some(8), None,     None,     None,     Some(7),  None    ],
+        [None,    None,    None,    None,    Some(1),  None,     Some(9),  None,     Some(4) ],
+        [Some(1), None,    None,    Some(4), None,     None,     None,     None,     Some(3) ],
+        [None,    None,    None,    None,    None,     None,     None,     None,     None    ],
+        [None,    None,    Some(7), None,    None,     None,     Some(8),  Some(6),  None    ],
+        [None,    None,    Some(6), Some(7), None,     Some(8),  Some(2),  None,     None    ],
+        [None,    Some(1), None,    None,    Some(9),  None,     None,     None,     Some(5) ],
+        [None,    None,    None,    None,    None,     Some(1),  None,     None,     Some(8) ],
+    ];
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    let mut solver = CrookSolver::new(puzzle);
+    let stats = solver.solve();
+
+    assert_eq!(stats.solutions_found, 1);
+    assert!(solver.puzzle.is_solved());
+}
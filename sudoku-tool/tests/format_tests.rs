@@ -0,0 +1,72 @@
+use sudoku_tool::core::sudoku::Sudoku;
+
+const FLAT_9X9: &str =
+    "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+
+#[test]
+fn test_from_str_line_dot_and_zero_notation() {
+    let puzzle = Sudoku::from_str_line(FLAT_9X9).expect("valid flat line should parse");
+    assert_eq!(puzzle.get_solved_value(0, 0), Some(5));
+    assert_eq!(puzzle.get_solved_value(0, 1), Some(3));
+    assert_eq!(puzzle.get_solved_value(0, 2), None);
+}
+
+#[test]
+fn test_from_str_line_rejects_embedded_whitespace() {
+    let spaced =
+        "5 3 0 0 7 0 0 0 0600195000098000060800060003400803001700020006060000280000419005000080079";
+    assert!(Sudoku::from_str_line(spaced).is_err());
+}
+
+#[test]
+fn test_from_str_line_rejects_wrong_length() {
+    assert!(Sudoku::from_str_line("123").is_err());
+}
+
+#[test]
+fn test_from_str_line_rejects_invalid_character() {
+    let bad = FLAT_9X9.replacen('5', "X", 1);
+    assert!(Sudoku::from_str_line(&bad).is_err());
+}
+
+#[test]
+fn test_from_ksudoku_parses_values_and_blanks() {
+    let values: Vec<String> = FLAT_9X9.chars().map(|c| c.to_string()).collect();
+    let puzzle_string = format!("9x9:d:{}", values.join(","));
+
+    let puzzle = Sudoku::from_ksudoku(&puzzle_string).expect("valid ksudoku string should parse");
+    assert_eq!(puzzle.get_solved_value(0, 0), Some(5));
+    assert_eq!(puzzle.get_solved_value(0, 2), None);
+}
+
+#[test]
+fn test_from_ksudoku_rejects_non_square_dimensions() {
+    assert!(Sudoku::from_ksudoku("9x16:d:0,0,0").is_err());
+}
+
+#[test]
+fn test_from_ksudoku_rejects_wrong_value_count() {
+    assert!(Sudoku::from_ksudoku("9x9:d:0,0,0").is_err());
+}
+
+#[test]
+fn test_from_ksudoku_rejects_missing_fields() {
+    assert!(Sudoku::from_ksudoku("9x9:d").is_err());
+}
+
+#[test]
+fn test_to_line_string_round_trips_through_from_str_line() {
+    let puzzle = Sudoku::from_str_line(FLAT_9X9).unwrap();
+    let rendered = puzzle.to_line_string();
+    assert_eq!(rendered.len(), 81);
+    assert_eq!(
+        Sudoku::from_str_line(&rendered).unwrap().to_line_string(),
+        rendered
+    );
+}
+
+#[test]
+fn test_to_grid_display_matches_display_impl() {
+    let puzzle = Sudoku::from_str_line(FLAT_9X9).unwrap();
+    assert_eq!(puzzle.to_grid_display(), format!("{}", puzzle));
+}
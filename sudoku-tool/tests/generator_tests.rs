@@ -0,0 +1,43 @@
+use sudoku_tool::core::generator::{generate, Difficulty};
+use sudoku_tool::core::solvers::bf_solver::has_unique_solution;
+use sudoku_tool::core::solvers::constraints::classic_constraints;
+use sudoku_tool::core::solvers::logic_solver::{solve_logic, LogicSolveOutcome, SolveStep};
+
+#[test]
+fn test_generated_puzzle_has_unique_solution() {
+    let constraint = classic_constraints(3);
+    let puzzle = generate(Difficulty::Medium, &constraint);
+    assert!(has_unique_solution(&puzzle));
+}
+
+#[test]
+fn test_easy_puzzle_needs_only_naked_singles() {
+    let constraint = classic_constraints(3);
+    let puzzle = generate(Difficulty::Easy, &constraint);
+
+    match solve_logic(&puzzle) {
+        LogicSolveOutcome::SolvedByLogic { steps, .. } => {
+            assert!(steps
+                .iter()
+                .all(|step| matches!(step, SolveStep::NakedSingle { .. })));
+        }
+        LogicSolveOutcome::FellBackToSearch { .. } => {
+            panic!("an Easy puzzle should never need the backtracking fallback")
+        }
+    }
+}
+
+#[test]
+fn test_hard_puzzle_requires_backtracking() {
+    let constraint = classic_constraints(3);
+    let puzzle = generate(Difficulty::Hard, &constraint);
+
+    match solve_logic(&puzzle) {
+        LogicSolveOutcome::FellBackToSearch { solution, .. } => {
+            assert!(solution.unwrap().is_solved());
+        }
+        LogicSolveOutcome::SolvedByLogic { .. } => {
+            panic!("a Hard puzzle should have been dug past what logic alone can finish")
+        }
+    }
+}
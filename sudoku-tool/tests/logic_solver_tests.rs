@@ -0,0 +1,109 @@
+use sudoku_tool::core::solvers::logic_solver::{solve_logic, LogicSolveOutcome, SolveStep};
+use sudoku_tool::core::sudoku::Sudoku;
+
+#[test]
+fn test_solves_near_complete_puzzle_with_naked_singles() {
+    #[rustfmt::skip]
+    let preset = [
+        [Some(5), Some(3), Some(4), Some(6), Some(7), Some(8), Some(9), Some(1), None   ],
+        [Some(6), Some(7), Some(2), Some(1), Some(9), Some(5), Some(3), Some(4), Some(8)],
+        [Some(1), Some(9), Some(8), Some(3), Some(4), Some(2), Some(5), Some(6), Some(7)],
+        [Some(8), Some(5), Some(9), Some(7), Some(6), Some(1), Some(4), Some(2), Some(3)],
+        [Some(4), Some(2), Some(6), Some(8), Some(5), Some(3), Some(7), Some(9), Some(1)],
+        [Some(7), Some(1), Some(3), Some(9), Some(2), Some(4), Some(8), Some(5), Some(6)],
+        [Some(9), Some(6), Some(1), Some(5), Some(3), Some(7), Some(2), Some(8), Some(4)],
+        [Some(2), Some(8), Some(7), Some(4), Some(1), Some(9), Some(6), Some(3), Some(5)],
+        [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(9)],
+    ];
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    match solve_logic(&puzzle) {
+        LogicSolveOutcome::SolvedByLogic { solution, steps } => {
+            assert!(solution.is_solved());
+            assert_eq!(steps.len(), 1);
+            assert!(matches!(steps[0], SolveStep::NakedSingle { cell: (0, 8), value: 2 }));
+        }
+        LogicSolveOutcome::FellBackToSearch { .. } => {
+            panic!("a single missing cell should be solvable by naked singles alone")
+        }
+    }
+}
+
+#[test]
+fn test_solve_step_render_uses_chess_coordinates() {
+    let step = SolveStep::NakedSingle {
+        cell: (4, 2),
+        value: 7,
+    };
+    assert_eq!(step.render(), "C5 = 7 (naked single)");
+}
+
+#[test]
+fn test_falls_back_to_search_on_empty_board() {
+    let puzzle = Sudoku::new();
+    match solve_logic(&puzzle) {
+        LogicSolveOutcome::FellBackToSearch { steps, solution } => {
+            assert!(solution.is_some(), "search fallback should still find a solution");
+            assert!(
+                steps.iter().all(|s| matches!(s, SolveStep::Guess { .. })),
+                "an empty board can't make any logical deduction"
+            );
+            assert_eq!(steps.len(), 81);
+        }
+        LogicSolveOutcome::SolvedByLogic { .. } => {
+            panic!("an empty board has far too many possibilities for pure deduction")
+        }
+    }
+}
+
+#[test]
+fn test_hidden_single_step_reports_correct_group() {
+    // Verified offline to need only naked/hidden singles, and to have at
+    // least one cell in row 0 that's forced by a hidden single despite
+    // having more than one naked candidate.
+    #[rustfmt::skip]
+    let preset = [
+        [None,    Some(3), None,    Some(6), None,    Some(8), None,    None,    None   ],
+        [None,    Some(7), None,    Some(1), None,    Some(5), None,    Some(4), Some(8)],
+        [None,    None,    None,    None,    None,    None,    Some(5), Some(6), Some(7)],
+        [None,    None,    Some(9), Some(7), Some(6), None,    Some(4), None,    None   ],
+        [Some(4), None,    None,    None,    None,    None,    None,    None,    Some(1)],
+        [None,    None,    Some(3), Some(9), Some(2), None,    Some(8), None,    Some(6)],
+        [None,    None,    None,    Some(5), Some(3), None,    None,    None,    Some(4)],
+        [Some(2), Some(8), None,    None,    Some(1), None,    None,    Some(3), None   ],
+        [Some(3), Some(4), None,    Some(2), Some(8), None,    Some(1), None,    None   ],
+    ];
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+
+    match solve_logic(&puzzle) {
+        LogicSolveOutcome::SolvedByLogic { solution, steps } => {
+            assert!(solution.is_solved());
+            assert!(
+                steps.iter().any(|s| matches!(
+                    s,
+                    SolveStep::HiddenSingle {
+                        group: sudoku_tool::core::solvers::logic_solver::Group::Row(0),
+                        ..
+                    }
+                )),
+                "at least one of row 0's missing digits should be forced by a hidden single"
+            );
+        }
+        LogicSolveOutcome::FellBackToSearch { .. } => {
+            panic!("this puzzle should be fully solvable by naked/hidden singles")
+        }
+    }
+}
+
+#[test]
+fn test_solve_16x16_puzzle_falls_back_to_search() {
+    let puzzle = Sudoku::new_with_box_size(4);
+    match solve_logic(&puzzle) {
+        LogicSolveOutcome::FellBackToSearch { solution, .. } => {
+            assert!(solution.unwrap().is_solved());
+        }
+        LogicSolveOutcome::SolvedByLogic { solution, .. } => {
+            assert!(solution.is_solved());
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use sudoku_tool::core::puzzle_parser::{parse_puzzle, PuzzleFormat};
+
+const FLAT_9X9: &str = "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+
+#[test]
+fn test_flat_string_detected() {
+    let (puzzle, format) = parse_puzzle(FLAT_9X9).expect("flat string should parse");
+    assert_eq!(format, PuzzleFormat::FlatString);
+    assert_eq!(puzzle.get_solved_value(0, 0), Some(5));
+    assert_eq!(puzzle.get_solved_value(0, 1), Some(3));
+    assert_eq!(puzzle.get_solved_value(0, 2), None);
+}
+
+#[test]
+fn test_line_grid_detected() {
+    let input = "5 3 . . 7 . . . .\n\
+                 6 . . 1 9 5 . . .\n\
+                 . 9 8 . . . . 6 .\n\
+                 8 . . . 6 . . . 3\n\
+                 4 . . 8 . 3 . . 1\n\
+                 7 . . . 2 . . . 6\n\
+                 . 6 . . . . 2 8 .\n\
+                 . . . 4 1 9 . . 5\n\
+                 . . . . 8 . . 7 9";
+    let (puzzle, format) = parse_puzzle(input).expect("line grid should parse");
+    assert_eq!(format, PuzzleFormat::LineGrid);
+    assert_eq!(puzzle.get_solved_value(0, 0), Some(5));
+    assert_eq!(puzzle.get_solved_value(8, 8), Some(9));
+}
+
+#[test]
+fn test_box_divider_grid_detected() {
+    let input = "5 3 . | . 7 . | . . .\n\
+                 6 . . | 1 9 5 | . . .\n\
+                 . 9 8 | . . . | . 6 .\n\
+                 ------+-------+------\n\
+                 8 . . | . 6 . | . . 3\n\
+                 4 . . | 8 . 3 | . . 1\n\
+                 7 . . | . 2 . | . . 6\n\
+                 ------+-------+------\n\
+                 . 6 . | . . . | 2 8 .\n\
+                 . . . | 4 1 9 | . . 5\n\
+                 . . . | . 8 . | . 7 9";
+    let (puzzle, format) = parse_puzzle(input).expect("box-divider grid should parse");
+    assert_eq!(format, PuzzleFormat::BoxDividerGrid);
+    assert_eq!(puzzle.get_solved_value(0, 0), Some(5));
+    assert_eq!(puzzle.get_solved_value(8, 8), Some(9));
+}
+
+#[test]
+fn test_invalid_input_rejected() {
+    assert!(parse_puzzle("not a puzzle at all").is_err());
+}
@@ -0,0 +1,51 @@
+use sudoku_tool::core::solvers::sat_solver;
+use sudoku_tool::core::sudoku::Sudoku;
+
+#[test]
+fn test_solve_already_solved_puzzle() {
+    #[rustfmt::skip]
+    let preset = [
+        [Some(5), Some(3), Some(4), Some(6), Some(7), Some(8), Some(9), Some(1), Some(2)],
+        [Some(6), Some(7), Some(2), Some(1), Some(9), Some(5), Some(3), Some(4), Some(8)],
+        [Some(1), Some(9), Some(8), Some(3), Some(4), Some(2), Some(5), Some(6), Some(7)],
+        [Some(8), Some(5), Some(9), Some(7), Some(6), Some(1), Some(4), Some(2), Some(3)],
+        [Some(4), Some(2), Some(6), Some(8), Some(5), Some(3), Some(7), Some(9), Some(1)],
+        [Some(7), Some(1), Some(3), Some(9), Some(2), Some(4), Some(8), Some(5), Some(6)],
+        [Some(9), Some(6), Some(1), Some(5), Some(3), Some(7), Some(2), Some(8), Some(4)],
+        [Some(2), Some(8), Some(7), Some(4), Some(1), Some(9), Some(6), Some(3), Some(5)],
+        [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(9)],
+    ];
+
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+    let solution = sat_solver::solve(&puzzle).expect("already-solved puzzle should be SAT");
+    assert!(solution.is_solved());
+}
+
+#[test]
+fn test_solve_partial_puzzle() {
+    #[rustfmt::skip]
+    let preset = [
+        [None,    Some(3), Some(9), Some(5), None,     None,     None,     None,     None    ],
+        [None,    None,    None,    Some(8), None,     None,     None,     Some(7),  None    ],
+        [None,    None,    None,    None,    Some(1),  None,     Some(9),  None,     Some(4) ],
+        [Some(1), None,    None,    Some(4), None,     None,     None,     None,     Some(3) ],
+        [None,    None,    None,    None,    None,     None,     None,     None,     None    ],
+        [None,    None,    Some(7), None,    None,     None,     Some(8),  Some(6),  None    ],
+        [None,    None,    Some(6), Some(7), None,     Some(8),  Some(2),  None,     None    ],
+        [None,    Some(1), None,    None,    Some(9),  None,     None,     None,     Some(5) ],
+        [None,    None,    None,    None,    None,     Some(1),  None,     None,     Some(8) ],
+    ];
+
+    let puzzle = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
+    let solution = sat_solver::solve(&puzzle).expect("puzzle should be satisfiable");
+    assert!(solution.is_solved());
+
+    // Every preset hint must survive into the solution unchanged.
+    for row in 0..9 {
+        for col in 0..9 {
+            if let Some(value) = puzzle.get_solved_value(row, col) {
+                assert_eq!(solution.get_solved_value(row, col), Some(value));
+            }
+        }
+    }
+}
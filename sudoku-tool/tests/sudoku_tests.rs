@@ -24,7 +24,7 @@ fn test_solved_board() {
         [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(9)],
     ];
 
-    let solved_sudoku = Sudoku::from_preset(preset);
+    let solved_sudoku = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
     assert!(
         solved_sudoku.is_solved(),
         "Solved board should be marked as solved"
@@ -68,7 +68,7 @@ fn test_invalid_preset_value() {
         [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(10)], // Invalid value
     ];
 
-    let _ = Sudoku::from_preset(invalid_preset);
+    let _ = Sudoku::from_preset(invalid_preset.iter().map(|r| r.to_vec()).collect());
 }
 
 #[test]
@@ -86,7 +86,7 @@ fn test_modify_solved_board() {
         [Some(3), Some(4), Some(5), Some(2), Some(8), Some(6), Some(1), Some(7), Some(9)],
     ];
 
-    let mut solved_sudoku = Sudoku::from_preset(preset);
+    let mut solved_sudoku = Sudoku::from_preset(preset.iter().map(|r| r.to_vec()).collect());
     assert!(solved_sudoku.is_solved());
 
     // Modify a cell to create a conflict
@@ -163,3 +163,96 @@ fn test_to_string() {
     // First few characters should match
     assert_eq!(&converted_string[0..4], "1..5");
 }
+
+#[test]
+fn test_remove_possibility() {
+    let mut sudoku = Sudoku::new();
+    assert!(sudoku.get_solved_value(0, 0).is_none());
+
+    // The candidate mask starts empty for a board built with `new()`
+    // directly (no preset), so there's nothing to remove yet.
+    assert!(!sudoku.remove_possibility(0, 0, 5));
+
+    let preset = vec![vec![None; 9]; 9];
+    let mut sudoku = Sudoku::from_preset(preset);
+    assert!(sudoku.remove_possibility(0, 0, 5));
+    assert!(!sudoku.remove_possibility(0, 0, 5)); // already removed
+
+    // Can't remove from an already-solved cell.
+    sudoku.set_cell(1, 1, 3).unwrap();
+    assert!(!sudoku.remove_possibility(1, 1, 3));
+}
+
+#[test]
+fn test_from_coords_sparse_hints() {
+    let input = "9,9\n0,0,5\n0,3,6\n8,8,9\n";
+    let puzzle = Sudoku::from_coords(input).expect("valid coordinate triples should parse");
+
+    assert_eq!(puzzle.get_solved_value(0, 0), Some(5));
+    assert_eq!(puzzle.get_solved_value(0, 3), Some(6));
+    assert_eq!(puzzle.get_solved_value(8, 8), Some(9));
+    assert_eq!(puzzle.get_solved_value(1, 1), None);
+}
+
+#[test]
+fn test_from_coords_zero_means_empty() {
+    let puzzle = Sudoku::from_coords("9,9\n0,0,0\n").expect("a zero value should parse as empty");
+    assert_eq!(puzzle.get_solved_value(0, 0), None);
+}
+
+#[test]
+fn test_from_coords_rejects_duplicate_coordinate() {
+    let result = Sudoku::from_coords("9,9\n0,0,5\n0,0,6\n");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_coords_rejects_out_of_range() {
+    assert!(Sudoku::from_coords("9,9\n9,0,5\n").is_err());
+    assert!(Sudoku::from_coords("9,9\n0,0,10\n").is_err());
+}
+
+#[test]
+fn test_standard_matches_new() {
+    let standard = Sudoku::standard();
+    assert_eq!(standard.box_size, 3);
+    assert_eq!(standard.side(), 9);
+}
+
+#[test]
+fn test_16x16_board_is_independent_order() {
+    let puzzle = Sudoku::new_with_box_size(4);
+    assert_eq!(puzzle.side(), 16);
+    assert!(!puzzle.is_solved());
+}
+
+#[test]
+fn test_25x25_board_set_cell_round_trips_high_values() {
+    // Regression test for a `CandidateMask` too narrow to represent digits
+    // above 16: box_size 5 needs a bit for every value 1-25.
+    let mut puzzle = Sudoku::new_with_box_size(5);
+    assert_eq!(puzzle.side(), 25);
+    assert!(puzzle.set_cell(0, 0, 25).is_ok());
+    assert_eq!(puzzle.get_solved_value(0, 0), Some(25));
+}
+
+#[test]
+fn test_25x25_solved_board_via_from_preset() {
+    let side = 25;
+    let box_size = 5;
+    // A standard shifted-rows construction that's valid for any box_size:
+    // rotates each row within its box band, then staggers by band.
+    let preset: Vec<Vec<Option<u8>>> = (0..side)
+        .map(|r| {
+            (0..side)
+                .map(|c| Some((((box_size * (r % box_size) + r / box_size + c) % side) + 1) as u8))
+                .collect()
+        })
+        .collect();
+
+    let solved_sudoku = Sudoku::from_preset(preset);
+    assert!(
+        solved_sudoku.is_solved(),
+        "25x25 solved board should be marked as solved"
+    );
+}